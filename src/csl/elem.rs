@@ -283,7 +283,7 @@ impl ElemChild {
         match self {
             ElemChild::Text(t) => {
                 t.formatting.write_start(w, format)?;
-                w.write_str(&t.text)?;
+                write_text(w, &t.text, format)?;
                 t.formatting.write_end(w, format)?;
                 Ok(())
             }
@@ -291,10 +291,10 @@ impl ElemChild {
             ElemChild::Markup(m) => w.write_str(m),
             ElemChild::Link { text, url } if format == BufWriteFormat::Html => {
                 w.write_str("<a href=\"")?;
-                w.write_str(url)?;
+                write_text(w, url, format)?;
                 w.write_str("\">")?;
                 text.formatting.write_start(w, format)?;
-                w.write_str(&text.text)?;
+                write_text(w, &text.text, format)?;
                 text.formatting.write_end(w, format)?;
                 w.write_str("</a>")
             }
@@ -362,6 +362,30 @@ impl From<Formatted> for ElemChild {
     }
 }
 
+/// Write text, escaping characters that would be interpreted as markup in the
+/// output format.
+fn write_text(
+    w: &mut impl fmt::Write,
+    text: &str,
+    format: BufWriteFormat,
+) -> Result<(), fmt::Error> {
+    if format != BufWriteFormat::Html {
+        return w.write_str(text);
+    }
+
+    for c in text.chars() {
+        match c {
+            '&' => w.write_str("&amp;")?,
+            '<' => w.write_str("&lt;")?,
+            '>' => w.write_str("&gt;")?,
+            '"' => w.write_str("&quot;")?,
+            c => w.write_char(c)?,
+        }
+    }
+
+    Ok(())
+}
+
 /// The format with which to write an [element](Elem).
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
 pub enum BufWriteFormat {
@@ -383,6 +407,54 @@ pub struct Formatted {
     pub formatting: Formatting,
 }
 
+impl Formatted {
+    /// The text without its formatting.
+    pub fn plain(&self) -> String {
+        self.text.clone()
+    }
+
+    /// Render the text as HTML, with the formatting as inline CSS.
+    pub fn to_html(&self) -> String {
+        let mut buf = String::new();
+        let format = BufWriteFormat::Html;
+        self.formatting.write_start(&mut buf, format).unwrap();
+        write_text(&mut buf, &self.text, format).unwrap();
+        self.formatting.write_end(&mut buf, format).unwrap();
+        buf
+    }
+
+    /// Render the text as Markdown. Formatting that Markdown cannot express,
+    /// like small caps, is dropped.
+    pub fn to_markdown(&self) -> String {
+        let mut delim = String::new();
+        if self.formatting.font_weight == FontWeight::Bold {
+            delim.push_str("**");
+        }
+        if self.formatting.font_style == FontStyle::Italic {
+            delim.push('_');
+        }
+
+        let mut escaped = String::with_capacity(self.text.len());
+        for c in self.text.chars() {
+            if matches!(c, '\\' | '*' | '_' | '`' | '[' | ']' | '<' | '>') {
+                escaped.push('\\');
+            }
+            escaped.push(c);
+        }
+
+        // Emphasis must not start or end with whitespace.
+        let inner = escaped.trim();
+        if delim.is_empty() || inner.is_empty() {
+            return escaped;
+        }
+
+        let start = escaped.len() - escaped.trim_start().len();
+        let end = start + inner.len();
+        let closing: String = delim.chars().rev().collect();
+        format!("{}{delim}{inner}{closing}{}", &escaped[..start], &escaped[end..])
+    }
+}
+
 /// Some formatting information.
 #[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct Formatting {
@@ -611,3 +683,31 @@ impl<T: Default> Default for NonEmptyStack<T> {
         Self::new(T::default())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn formatted_markup() {
+        let bold = Formatting {
+            font_weight: FontWeight::Bold,
+            ..Default::default()
+        };
+        let text = bold.add_text("Bold & *loud* ".to_string());
+        assert_eq!(text.plain(), "Bold & *loud* ");
+        assert_eq!(text.to_markdown(), "**Bold & \\*loud\\*** ");
+        assert_eq!(
+            text.to_html(),
+            "<span style=\"font-weight: bold;\">Bold &amp; *loud* </span>"
+        );
+
+        let italic = Formatting { font_style: FontStyle::Italic, ..bold };
+        let text = italic.add_text("Title".to_string());
+        assert_eq!(text.to_markdown(), "**_Title_**");
+
+        let plain = Formatting::default().add_text("a_b".to_string());
+        assert_eq!(plain.to_markdown(), "a\\_b");
+        assert_eq!(plain.to_html(), "a_b");
+    }
+}
@@ -45,14 +45,34 @@ mod taxonomy;
 pub struct BibliographyDriver<'a, T: EntryLike> {
     /// The citations we have seen so far.
     citations: Vec<CitationRequest<'a, T>>,
+    /// How to render bibliography entries without authors and editors.
+    anonymous: AnonymousPolicy,
 }
 
 impl<T: EntryLike> Default for BibliographyDriver<'_, T> {
     fn default() -> Self {
-        Self { citations: Vec::new() }
+        Self {
+            citations: Vec::new(),
+            anonymous: AnonymousPolicy::default(),
+        }
     }
 }
 
+/// What to print in the author position of a bibliography entry that has
+/// neither authors nor editors.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AnonymousPolicy {
+    /// Follow the substitution rules of the style, which usually put the
+    /// title in place of the author.
+    #[default]
+    UseTitle,
+    /// Print the locale's "anonymous" term, if it defines one. Its
+    /// capitalization is left to the `text-case` of the style.
+    Anonymous,
+    /// Leave the author position empty.
+    Empty,
+}
+
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
 struct SpeculativeItemRender<'a, T: EntryLike> {
     rendered: ElemChildren,
@@ -81,6 +101,12 @@ impl<'a, T: EntryLike> BibliographyDriver<'a, T> {
         Self::default()
     }
 
+    /// Set how bibliography entries without authors and editors are rendered.
+    /// Citations are not affected.
+    pub fn set_anonymous_policy(&mut self, policy: AnonymousPolicy) {
+        self.anonymous = policy;
+    }
+
     /// Create a new citation with the given items.
     pub fn citation(&mut self, mut req: CitationRequest<'a, T>) {
         let style = req.style();
@@ -133,7 +159,7 @@ impl<'a, T: EntryLike + Hash + PartialEq + Eq + Debug> BibliographyDriver<'a, T>
             for item in items.iter() {
                 let entry = &item.entry;
 
-                let is_near_note = citation.note_number.map_or(false, |_| {
+                let is_near_note = citation.note_number.is_some_and(|_| {
                     res.iter()
                         .rev()
                         .take(style.csl.citation.near_note_distance as usize)
@@ -441,7 +467,7 @@ impl<'a, T: EntryLike + Hash + PartialEq + Eq + Debug> BibliographyDriver<'a, T>
 
                     if let Some(suffix) = cite.request.suffix() {
                         let print = last_text_mut_child(&mut elem_children)
-                            .map_or(true, |t| !t.text.ends_with(suffix));
+                            .is_none_or(|t| !t.text.ends_with(suffix));
                         if print {
                             elem_children.push(
                                 Formatted { text: suffix.to_string(), formatting }.into(),
@@ -477,6 +503,7 @@ impl<'a, T: EntryLike + Hash + PartialEq + Eq + Debug> BibliographyDriver<'a, T>
                                 },
                                 cited_item.locale.as_ref(),
                                 request.locale.as_ref(),
+                                self.anonymous,
                             )
                             .unwrap(),
                     ),
@@ -582,7 +609,7 @@ pub fn standalone_citation<T: EntryLike>(
         }
 
         if let Some(suffix) = style.csl.citation.layout.suffix.as_ref() {
-            let print = res.last_text().map_or(true, |t| !t.text.ends_with(suffix));
+            let print = res.last_text().is_none_or(|t| !t.text.ends_with(suffix));
             if print {
                 res.0.push(Formatted { text: suffix.clone(), formatting }.into());
             }
@@ -1098,6 +1125,7 @@ impl<'a> StyleContext<'a> {
             style: self,
             writing: WritingContext::new(self.csl.settings.options.clone()),
             bibliography,
+            anonymous: AnonymousPolicy::default(),
         }
     }
 
@@ -1121,6 +1149,7 @@ impl<'a> StyleContext<'a> {
             style: self,
             writing: WritingContext::new(self.csl.settings.options.clone()),
             bibliography,
+            anonymous: AnonymousPolicy::default(),
         }
     }
 
@@ -1146,8 +1175,9 @@ impl<'a> StyleContext<'a> {
         props: CiteProperties<'a>,
         locale: Option<&LocaleCode>,
         term_locale: Option<&LocaleCode>,
+        anonymous: AnonymousPolicy,
     ) -> Option<ElemChildren> {
-        self.do_bibliography(entry, props, locale, term_locale)
+        self.do_bibliography(entry, props, locale, term_locale, anonymous)
             .map(|ctx| ctx.flush())
     }
 
@@ -1259,9 +1289,14 @@ impl<'a> StyleContext<'a> {
             }
             Some(CitePurpose::Prose) => {
                 do_author(&mut ctx);
-                if !self.csl.citation.layout.prefix.as_ref().map_or(false, |f| {
-                    f.chars().next().map_or(false, char::is_whitespace)
-                }) {
+                if !self
+                    .csl
+                    .citation
+                    .layout
+                    .prefix
+                    .as_ref()
+                    .is_some_and(|f| f.chars().next().is_some_and(char::is_whitespace))
+                {
                     ctx.ensure_space();
                 }
 
@@ -1309,8 +1344,10 @@ impl<'a> StyleContext<'a> {
         props: CiteProperties<'a>,
         locale: Option<&'b LocaleCode>,
         term_locale: Option<&'b LocaleCode>,
+        anonymous: AnonymousPolicy,
     ) -> Option<Context<'b, T>> {
         let mut ctx = self.ctx(entry, props, locale, term_locale, true);
+        ctx.anonymous = anonymous;
         ctx.writing
             .push_name_options(&self.csl.bibliography.as_ref()?.name_options);
         self.csl.bibliography.as_ref()?.layout.render(&mut ctx);
@@ -1599,6 +1636,9 @@ pub(crate) struct WritingContext {
     format_stack: NonEmptyStack<Formatting>,
     /// Text cases.
     cases: NonEmptyStack<Option<TextCase>>,
+    /// Whether the variable that is being written is in English, if it has a
+    /// language of its own.
+    variable_english: Option<bool>,
     /// Inheritable name options.
     name_options: NonEmptyStack<InheritableNameOptions>,
     /// Usage info for the current nesting level.
@@ -1626,6 +1666,7 @@ impl Default for WritingContext {
             first_name: None,
             format_stack: NonEmptyStack::default(),
             cases: NonEmptyStack::default(),
+            variable_english: None,
             name_options: NonEmptyStack::default(),
             usage_info: RefCell::default(),
             buf: CaseFolder::default(),
@@ -1949,6 +1990,8 @@ pub(crate) struct Context<'a, T: EntryLike> {
     style: &'a StyleContext<'a>,
     writing: WritingContext,
     bibliography: bool,
+    /// How to render bibliography entries without authors and editors.
+    anonymous: AnonymousPolicy,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -2316,9 +2359,10 @@ impl<'a, T: EntryLike> Context<'a, T> {
     fn push_str(&mut self, s: &str) {
         let s = self.do_pull_punctuation(s);
 
-        self.writing.buf.reconfigure(
-            (*self.writing.cases.last()).map(Into::into).unwrap_or_default(),
-        );
+        // Language-dependent cases only apply to English text.
+        let case = (*self.writing.cases.last())
+            .filter(|c| c.is_language_independent() || self.is_english());
+        self.writing.buf.reconfigure(case.map(Into::into).unwrap_or_default());
 
         fn last_buffer(ctx: &mut WritingContext) -> Option<&mut String> {
             let last = ctx
@@ -2334,13 +2378,12 @@ impl<'a, T: EntryLike> Context<'a, T> {
             }
         }
 
-        let ends_with_space = last_buffer(&mut self.writing).map_or(false, |s| {
-            s.chars().next_back().map_or(false, |c| c.is_whitespace())
-        });
+        let ends_with_space = last_buffer(&mut self.writing)
+            .is_some_and(|s| s.chars().next_back().is_some_and(|c| c.is_whitespace()));
 
         // Punctuation eats spaces. Whitespace should be trimmed.
         if ends_with_space
-            && s.chars().next().map_or(false, |c| {
+            && s.chars().next().is_some_and(|c| {
                 c.is_whitespace() || c == '.' || c == ',' || c == ']' || c == ')'
             })
         {
@@ -2377,6 +2420,15 @@ impl<'a, T: EntryLike> Context<'a, T> {
         self.writing.pull_punctuation = false;
     }
 
+    /// Push the value of a standard variable to the buffer. Cases follow the
+    /// language of the variable if it has one.
+    pub fn push_variable(&mut self, variable: StandardVariable, chunked: &ChunkedString) {
+        let english = self.instance.entry.is_english_variable(variable);
+        let prev = std::mem::replace(&mut self.writing.variable_english, english);
+        self.push_chunked(chunked);
+        self.writing.variable_english = prev;
+    }
+
     /// Push a chunked string to the buffer.
     pub fn push_chunked(&mut self, chunked: &ChunkedString) {
         for chunk in &chunked.0 {
@@ -2490,22 +2542,21 @@ impl<'a, T: EntryLike> Context<'a, T> {
 
     /// Set the case of the next text.
     fn push_case(&mut self, case: Option<TextCase>) -> CaseIdx {
-        if case.map_or(true, |c| c.is_language_independent())
-            || self
-                .instance
-                .entry
-                .is_english()
-                .or_else(|| self.instance.locale.map(LocaleCode::is_english))
-                .or_else(|| self.instance.term_locale.map(LocaleCode::is_english))
-                .or_else(|| {
-                    self.style.csl.default_locale.as_ref().map(LocaleCode::is_english)
-                })
-                .unwrap_or(true)
-        {
-            self.writing.push_case(case)
-        } else {
-            self.writing.push_case(None)
-        }
+        self.writing.push_case(case)
+    }
+
+    /// Whether the text that is being written is in English. A variable with
+    /// its own language takes precedence over the entry and the locales.
+    fn is_english(&self) -> bool {
+        self.writing
+            .variable_english
+            .or_else(|| self.instance.entry.is_english())
+            .or_else(|| self.instance.locale.map(LocaleCode::is_english))
+            .or_else(|| self.instance.term_locale.map(LocaleCode::is_english))
+            .or_else(|| {
+                self.style.csl.default_locale.as_ref().map(LocaleCode::is_english)
+            })
+            .unwrap_or(true)
     }
 
     /// Clear the case of the next text.
@@ -2707,7 +2758,7 @@ impl<'a, T: EntryLike> Context<'a, T> {
             .citation
             .layout
             .will_render(self, StandardVariable::YearSuffix.into())
-            && !self.style.csl.bibliography.as_ref().map_or(false, |b| {
+            && !self.style.csl.bibliography.as_ref().is_some_and(|b| {
                 b.layout.will_render(self, StandardVariable::YearSuffix.into())
             })
     }
@@ -2828,13 +2879,142 @@ enum SpecialForm {
 
 #[cfg(test)]
 mod tests {
-    use citationberg::LocaleFile;
+    use citationberg::{LocaleFile, PageRangeFormat};
 
     use crate::io::from_yaml_str;
 
     use super::*;
     use std::fs;
 
+    #[test]
+    fn ungrouped_numbers() {
+        let en_locale = fs::read_to_string("tests/data/locales-en-US.xml").unwrap();
+        let locales = [LocaleFile::from_xml(&en_locale).unwrap().into()];
+        let style = fs::read_to_string("tests/data/art-history.csl").unwrap();
+        let style = IndependentStyle::from_xml(&style).unwrap();
+
+        let yaml = r#"
+long:
+    type: Article
+    title: A Long Article
+    author: Doe, Jane
+    date: 2020
+    page-range: 1024-10240
+    parent:
+        type: Periodical
+        title: Journal
+        volume: 12345
+"#;
+        let bib = from_yaml_str(yaml).unwrap();
+        let mut driver = BibliographyDriver::new();
+        driver.citation(CitationRequest::from_items(
+            vec![CitationItem::with_entry(bib.get("long").unwrap())],
+            &style,
+            &locales,
+        ));
+        let result = driver.finish(BibliographyRequest {
+            style: &style,
+            locale: None,
+            locale_files: &locales,
+        });
+
+        let mut plain = String::new();
+        result.citations[0]
+            .citation
+            .write_buf(&mut plain, BufWriteFormat::Plain)
+            .unwrap();
+        assert!(plain.contains("1024–10240"), "{}", plain);
+        assert!(plain.contains("12345"), "{}", plain);
+        assert!(!plain.contains("1,024") && !plain.contains("12,345"));
+    }
+
+    #[test]
+    fn page_range_formats() {
+        let en_locale = fs::read_to_string("tests/data/locales-en-US.xml").unwrap();
+        let locales = [LocaleFile::from_xml(&en_locale).unwrap().into()];
+        let style = fs::read_to_string("tests/data/art-history.csl").unwrap();
+        let mut style = IndependentStyle::from_xml(&style).unwrap();
+
+        let cases = [
+            (PageRangeFormat::Expanded, ["123–128", "100–104", "1496–1497"]),
+            (PageRangeFormat::Minimal, ["123–8", "100–4", "1496–7"]),
+            (PageRangeFormat::MinimalTwo, ["123–28", "100–04", "1496–97"]),
+            (PageRangeFormat::Chicago15, ["123–28", "100–104", "1496–97"]),
+            (PageRangeFormat::Chicago16, ["123–28", "100–104", "1496–97"]),
+        ];
+
+        for (format, expected) in cases {
+            style.settings.page_range_format = Some(format);
+            for (range, expected) in
+                ["123-128", "100-104", "1496-1497"].into_iter().zip(expected)
+            {
+                let yaml = format!(
+                    "article:\n    type: Article\n    title: An Article\n    page-range: {range}\n"
+                );
+                let bib = from_yaml_str(&yaml).unwrap();
+                let mut driver = BibliographyDriver::new();
+                driver.citation(CitationRequest::from_items(
+                    vec![CitationItem::with_entry(bib.get("article").unwrap())],
+                    &style,
+                    &locales,
+                ));
+                let result = driver.finish(BibliographyRequest {
+                    style: &style,
+                    locale: None,
+                    locale_files: &locales,
+                });
+
+                let mut plain = String::new();
+                result.citations[0]
+                    .citation
+                    .write_buf(&mut plain, BufWriteFormat::Plain)
+                    .unwrap();
+                assert!(plain.contains(expected), "{format:?}: {plain}");
+            }
+        }
+    }
+
+    #[test]
+    fn literal_markup() {
+        let en_locale = fs::read_to_string("tests/data/locales-en-US.xml").unwrap();
+        let locales = [LocaleFile::from_xml(&en_locale).unwrap().into()];
+        let style = fs::read_to_string("tests/data/art-history.csl").unwrap();
+        let style = IndependentStyle::from_xml(&style).unwrap();
+
+        let yaml = r#"
+stars:
+    type: Book
+    title: "Stars *and* <Bars> & \\{Stripes\\}"
+    author: Doe, Jane
+    date: 2020
+"#;
+        let bib = from_yaml_str(yaml).unwrap();
+        let entry = bib.get("stars").unwrap();
+        assert_eq!(entry.title().unwrap().to_string(), "Stars *and* <Bars> & {Stripes}");
+
+        let mut driver = BibliographyDriver::new();
+        driver.citation(CitationRequest::from_items(
+            vec![CitationItem::with_entry(entry)],
+            &style,
+            &locales,
+        ));
+        let result = driver.finish(BibliographyRequest {
+            style: &style,
+            locale: None,
+            locale_files: &locales,
+        });
+
+        let citation = &result.citations[0].citation;
+        let mut plain = String::new();
+        citation.write_buf(&mut plain, BufWriteFormat::Plain).unwrap();
+        assert!(plain.contains("Stars *and* <Bars> & {Stripes}"), "{}", plain);
+
+        let mut html = String::new();
+        citation.write_buf(&mut html, BufWriteFormat::Html).unwrap();
+        assert!(html.contains("Stars *and* &lt;Bars&gt; &amp; {Stripes}"), "{}", html);
+        assert!(!html.contains("<Bars>"));
+    }
+
     #[test]
     fn test_csl() {
         let en_locale = fs::read_to_string("tests/data/locales-en-US.xml").unwrap();
@@ -10,7 +10,7 @@ use citationberg::{
     DateStrongAnyForm, GrammarGender, LabelPluralize, LayoutRenderingElement,
     LongShortForm, NumberForm, TestPosition, TextCase, ToAffixes, ToFormatting,
 };
-use citationberg::{TermForm, TextTarget};
+use citationberg::{PageRangeFormat, TermForm, TextTarget};
 
 use crate::csl::taxonomy::NumberVariableResult;
 use crate::lang::{Case, SentenceCase, TitleCase};
@@ -77,7 +77,7 @@ impl RenderCsl for citationberg::Text {
                     );
                     ctx.push_link(&val, url);
                 }
-                _ => ctx.push_chunked(&val),
+                _ => ctx.push_variable(var, &val),
             },
             ResolvedTextTarget::NumberVariable(var, n) => match n {
                 NumberVariableResult::Regular(MaybeTyped::Typed(num))
@@ -99,7 +99,18 @@ impl RenderCsl for citationberg::Text {
                     ctx.writing.printed_non_empty_macro();
                 }
             }
-            ResolvedTextTarget::Term(s) => ctx.push_str(s),
+            ResolvedTextTarget::Term(s) => {
+                // The no-date term stands in for the missing date, so an
+                // empty year suffix next to it must not suppress its group.
+                if matches!(
+                    self.target,
+                    TextTarget::Term { term: Term::Other(OtherTerm::NoDate), .. }
+                ) {
+                    ctx.writing.usage_info.borrow_mut().last_mut().has_non_empty_vars =
+                        true;
+                }
+                ctx.push_str(s)
+            }
             ResolvedTextTarget::Value(val) => ctx.push_str(val),
         }
 
@@ -168,18 +179,17 @@ impl<'a, 'b> ResolvedTextTarget<'a, 'b> {
                 }
             }
             Some(SpecialForm::OnlyFirstDate | SpecialForm::OnlyYearSuffix)
-                if !matches!(&text.target, TextTarget::Macro { .. }) =>
-            {
                 if !matches!(
-                    text.target,
-                    TextTarget::Variable {
-                        var: Variable::Standard(StandardVariable::YearSuffix)
-                            | Variable::Number(NumberVariable::Locator),
-                        ..
-                    },
-                ) {
-                    return None;
-                }
+                    &text.target,
+                    TextTarget::Macro { .. }
+                        | TextTarget::Variable {
+                            var: Variable::Standard(StandardVariable::YearSuffix)
+                                | Variable::Number(NumberVariable::Locator),
+                            ..
+                        },
+                ) =>
+            {
+                return None;
             }
             _ => {}
         }
@@ -289,18 +299,59 @@ fn render_typed_num<T: EntryLike>(
 }
 
 fn render_page_range<T: EntryLike>(range: std::ops::Range<i32>, ctx: &mut Context<T>) {
-    ctx.style
-        .csl
-        .settings
-        .page_range_format
-        .unwrap_or_default()
-        .format(
-            range,
-            ctx,
-            ctx.term(OtherTerm::PageRangeDelimiter.into(), TermForm::default(), false)
-                .or(Some("–")),
-        )
-        .unwrap();
+    let format = ctx.style.csl.settings.page_range_format.unwrap_or_default();
+    let separator = ctx
+        .term(OtherTerm::PageRangeDelimiter.into(), TermForm::default(), false)
+        .unwrap_or("–");
+    let end = collapse_page_range_end(format, range.start, range.end);
+    write!(ctx, "{}{}{}", range.start, separator, end).unwrap();
+}
+
+/// Abbreviate the end of a page range according to the CSL page range
+/// format, e.g. `321–28` for the Chicago rules.
+fn collapse_page_range_end(format: PageRangeFormat, start: i32, end: i32) -> String {
+    let full = end.to_string();
+    if start < 0 || end <= start || start.to_string().len() != full.len() {
+        return full;
+    }
+
+    // Keep the digits that changed, but at least `min` of them.
+    let changed = |min: usize| {
+        let common = start
+            .to_string()
+            .bytes()
+            .zip(full.bytes())
+            .take_while(|(a, b)| a == b)
+            .count();
+        let keep = (full.len() - common).max(min).min(full.len());
+        full[full.len() - keep..].to_string()
+    };
+
+    match format {
+        PageRangeFormat::Expanded => full,
+        PageRangeFormat::Minimal => changed(1),
+        PageRangeFormat::MinimalTwo => changed(2),
+        PageRangeFormat::Chicago15 | PageRangeFormat::Chicago16
+            if start < 100 || start % 100 == 0 =>
+        {
+            full
+        }
+        // 101 through 109 (in multiples of 100) use the changed part only,
+        // omitting unneeded zeros.
+        PageRangeFormat::Chicago15 | PageRangeFormat::Chicago16 if start % 100 < 10 => {
+            let part = changed(1);
+            let trimmed = part.trim_start_matches('0');
+            if trimmed.is_empty() {
+                part
+            } else {
+                trimmed.to_string()
+            }
+        }
+        // The 15th edition keeps all digits of four-digit numbers if three of
+        // them change.
+        PageRangeFormat::Chicago15 if full.len() == 4 && changed(1).len() >= 3 => full,
+        PageRangeFormat::Chicago15 | PageRangeFormat::Chicago16 => changed(2),
+    }
 }
 
 impl RenderCsl for citationberg::Label {
@@ -309,15 +360,14 @@ impl RenderCsl for citationberg::Label {
             Some(SpecialForm::VarOnly(Variable::Number(n))) if self.variable != n => {
                 return
             }
+            // Labels belong to number variables, so none of them accompany
+            // another variable.
+            Some(SpecialForm::VarOnly(v)) if !matches!(v, Variable::Number(_)) => return,
             Some(
                 SpecialForm::VarOnly(_)
                 | SpecialForm::OnlyFirstDate
                 | SpecialForm::OnlyYearSuffix,
-            ) => {
-                if self.variable != NumberVariable::Locator {
-                    return;
-                }
-            }
+            ) if self.variable != NumberVariable::Locator => return,
             _ => {}
         }
 
@@ -328,7 +378,7 @@ impl RenderCsl for citationberg::Label {
                 .cite_props
                 .speculative
                 .locator
-                .map_or(false, |l| l.0 == Locator::Custom)
+                .is_some_and(|l| l.0 == Locator::Custom)
         {
             return;
         }
@@ -427,17 +477,11 @@ impl RenderCsl for citationberg::Date {
             }
 
             if month {
-                write!(
-                    ctx,
-                    "{:02}",
-                    date.month.map(|m| m as i32 + 1).unwrap_or_default()
-                )
-                .unwrap();
+                write!(ctx, "{:02}", date.calendar_month().unwrap_or_default()).unwrap();
             }
 
             if day {
-                write!(ctx, "{:02}", date.day.map(|d| d as i32 + 1).unwrap_or_default())
-                    .unwrap();
+                write!(ctx, "{:02}", date.calendar_day().unwrap_or_default()).unwrap();
             }
 
             return;
@@ -516,8 +560,8 @@ fn render_date_part<T: EntryLike>(
     first: bool,
 ) {
     let Some(val) = (match date_part.name {
-        DatePartName::Day => date.day.map(|i| i as i32 + 1),
-        DatePartName::Month => date.month.map(|i| i as i32 + 1),
+        DatePartName::Day => date.calendar_day(),
+        DatePartName::Month => date.calendar_month(),
         DatePartName::Year => {
             Some(if date.year > 0 { date.year } else { date.year.abs() + 1 })
         }
@@ -846,7 +890,7 @@ impl<'a, 'b, T: EntryLike> Iterator for BranchConditionIter<'a, 'b, T> {
                     Some(
                         self.ctx
                             .resolve_date_variable(var, true)
-                            .map_or(false, |d| d.approximate),
+                            .is_some_and(|d| d.approximate),
                     )
                 } else {
                     self.next_case();
@@ -870,7 +914,7 @@ impl<'a, 'b, T: EntryLike> Iterator for BranchConditionIter<'a, 'b, T> {
                             .speculative
                             .locator
                             .map(|l| l.0)
-                            .map_or(false, |l| l == loc),
+                            .is_some_and(|l| l == loc),
                     )
                 } else {
                     self.next_case();
@@ -935,7 +979,7 @@ impl<'a, 'b, T: EntryLike> Iterator for BranchConditionIter<'a, 'b, T> {
                                 s,
                                 true,
                             );
-                            val.map_or(false, |s| {
+                            val.is_some_and(|s| {
                                 !s.to_string().chars().all(char::is_whitespace)
                             })
                         }
@@ -1070,3 +1114,41 @@ impl From<TextCase> for Case {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn chicago_page_ranges() {
+        let collapse = |format, range: &str| {
+            let (start, end) = range.split_once('-').unwrap();
+            collapse_page_range_end(format, start.parse().unwrap(), end.parse().unwrap())
+        };
+
+        for (range, expected) in [
+            ("3-10", "10"),
+            ("71-72", "72"),
+            ("100-104", "104"),
+            ("1100-1123", "1123"),
+            ("107-108", "8"),
+            ("505-517", "17"),
+            ("1002-1006", "6"),
+            ("321-325", "25"),
+            ("415-532", "532"),
+            ("1536-1538", "38"),
+            ("11564-11615", "615"),
+            ("12991-13001", "3001"),
+            ("1496-1504", "1504"),
+            ("2787-2816", "2816"),
+        ] {
+            assert_eq!(collapse(PageRangeFormat::Chicago15, range), expected);
+        }
+
+        assert_eq!(collapse(PageRangeFormat::Chicago16, "1496-1504"), "504");
+        assert_eq!(collapse(PageRangeFormat::Minimal, "2787-2816"), "816");
+        assert_eq!(collapse(PageRangeFormat::MinimalTwo, "42-45"), "45");
+        assert_eq!(collapse(PageRangeFormat::Expanded, "321-328"), "328");
+        assert_eq!(collapse(PageRangeFormat::Minimal, "99-105"), "105");
+    }
+}
@@ -11,7 +11,7 @@ use citationberg::{
 use citationberg::{DisambiguationRule, TermForm};
 
 use crate::csl::taxonomy::EntryLike;
-use crate::csl::{Context, DisambiguateState, ElemMeta, SpecialForm};
+use crate::csl::{AnonymousPolicy, Context, DisambiguateState, ElemMeta, SpecialForm};
 use crate::types::Person;
 
 use super::{render_label_with_var, RenderCsl};
@@ -212,12 +212,12 @@ impl RenderCsl for Names {
         // Write the substitute if all variables are empty.
         let is_empty = people.iter().all(|(p, _)| p.is_empty());
         // Suppress this variable if we are in a special form.
-        match &ctx.instance.kind {
+        let suppress = match &ctx.instance.kind {
             Some(SpecialForm::VarOnly(Variable::Name(var))) => {
                 // Skip if none of the variables are the author and the supplement does not contain the author either.
                 let contains_v = self.variable.iter().any(|v| var == v);
                 let substitute_will_render_v = is_empty
-                    && self.substitute().map_or(false, |s| {
+                    && self.substitute().is_some_and(|s| {
                         s.children
                             .iter()
                             .filter_map(|c| match c {
@@ -229,21 +229,48 @@ impl RenderCsl for Names {
                             .flatten()
                             .any(|v| var == v)
                     });
-                if !contains_v && !substitute_will_render_v {
-                    return;
-                }
+                !contains_v && !substitute_will_render_v
             }
             Some(
                 SpecialForm::VarOnly(_)
                 | SpecialForm::OnlyFirstDate
                 | SpecialForm::OnlyYearSuffix,
-            ) => return,
+            ) => true,
             Some(SpecialForm::SuppressAuthor) => {
-                if self.variable.iter().any(|v| &NameVariable::Author == v) {
-                    return;
-                }
+                self.variable.iter().any(|v| &NameVariable::Author == v)
+            }
+            None => false,
+        };
+        if suppress {
+            return;
+        }
+
+        // Bibliography entries without authors and editors may bypass the
+        // substitution of the style.
+        let anonymous = ctx.anonymous != AnonymousPolicy::UseTitle
+            && is_empty
+            && renders_variable(self, NameVariable::Author, is_empty)
+            && ctx.resolve_name_variable(NameVariable::Author, true).is_empty()
+            && ctx.resolve_name_variable(NameVariable::Editor, true).is_empty();
+        if anonymous {
+            let term = (ctx.anonymous == AnonymousPolicy::Anonymous)
+                .then(|| {
+                    ctx.term(OtherTerm::Anonymous.into(), TermForm::default(), false)
+                })
+                .flatten();
+            if let Some(term) = term {
+                // The term stands in for the names, so groups must not be
+                // suppressed because of them.
+                ctx.writing.usage_info.borrow_mut().last_mut().has_non_empty_vars = true;
+                let depth = ctx.push_elem(self.to_formatting());
+                let affix_loc = ctx.apply_prefix(&self.to_affixes());
+                ctx.push_str(term);
+                ctx.apply_suffix(&self.to_affixes(), affix_loc);
+                ctx.commit_elem(depth, self.display, Some(ElemMeta::Names));
             }
-            None => {}
+
+            ctx.writing.pop_name_options();
+            return;
         }
 
         if is_empty {
@@ -389,6 +416,18 @@ impl RenderCsl for Names {
     }
 }
 
+/// Whether the names element renders a variable, either directly or, if its
+/// own variables are empty, through its substitute.
+fn renders_variable(names: &Names, var: NameVariable, is_empty: bool) -> bool {
+    names.variable.contains(&var)
+        || is_empty
+            && names.substitute().is_some_and(|s| {
+                s.children.iter().any(|c| {
+                    matches!(c, LayoutRenderingElement::Names(n) if n.variable.contains(&var))
+                })
+            })
+}
+
 #[derive(Debug, Clone, Copy)]
 enum EndDelim {
     Delim,
@@ -404,11 +443,16 @@ fn add_names<T: EntryLike>(
     forms: &[Option<DisambiguatedNameForm>],
     variable: NameVariable,
 ) {
-    let has_et_al = forms.iter().any(|f| f.is_none());
+    // An incomplete list always ends with "et al." and its last name is not
+    // known.
+    let truncated = ctx.instance.entry.is_name_list_truncated(variable);
+    let has_et_al = truncated || forms.iter().any(|f| f.is_none());
     let take = forms.iter().position(|f| f.is_none()).unwrap_or(persons.len());
     let names_opts = ctx.writing.name_options.last().clone();
     let name_opts = cs_name.options(&names_opts);
-    let et_al_use_last = has_et_al.then(|| forms.last().copied().flatten()).flatten();
+    let et_al_use_last = (has_et_al && !truncated)
+        .then(|| forms.last().copied().flatten())
+        .flatten();
     let mut last_inverted = false;
 
     let demote_non_dropping = match ctx.style.csl.settings.demote_non_dropping_particle {
@@ -3,7 +3,8 @@ use std::cmp;
 use std::str::FromStr;
 
 use crate::types::{
-    ChunkedString, Date, EntryType, MaybeTyped, Numeric, Person, PersonRole, StringChunk,
+    ChunkKind, ChunkedString, Date, EntryType, FormatString, MaybeTyped, Numeric, Person,
+    PersonRole, StringChunk,
 };
 use crate::Entry;
 use citationberg::taxonomy::{
@@ -29,6 +30,8 @@ pub trait EntryLike {
         variable: StandardVariable,
     ) -> Option<Cow<'_, ChunkedString>>;
     fn resolve_name_variable(&self, variable: NameVariable) -> Vec<Cow<'_, Person>>;
+    fn is_name_list_truncated(&self, variable: NameVariable) -> bool;
+    fn is_english_variable(&self, variable: StandardVariable) -> Option<bool>;
     fn resolve_date_variable(&self, variable: DateVariable) -> Option<Cow<'_, Date>>;
     fn matches_entry_type(&self, kind: taxonomy::Kind) -> bool;
     fn is_english(&self) -> Option<bool>;
@@ -122,18 +125,21 @@ impl EntryLike for Entry {
         variable: NumberVariable,
     ) -> Option<MaybeTyped<Cow<'_, Numeric>>> {
         match variable {
-            NumberVariable::ChapterNumber => self
-                .bound_select(
-                    &select!(
-                        (("e":Anthos) > ("p":Anthology)) |
-                        (("e":*) > ("p":Reference)) |
-                        (("e":Article) > ("p":Proceedings)) |
-                        (("e":*) > ("p":Book))
-                    ),
-                    "e",
-                )
-                .and_then(Entry::volume)
-                .map(MaybeTyped::to_cow),
+            NumberVariable::ChapterNumber => {
+                self.chapter().map(MaybeTyped::to_cow).or_else(|| {
+                    self.bound_select(
+                        &select!(
+                            (("e":Anthos) > ("p":Anthology)) |
+                            (("e":*) > ("p":Reference)) |
+                            (("e":Article) > ("p":Proceedings)) |
+                            (("e":*) > ("p":Book))
+                        ),
+                        "e",
+                    )
+                    .and_then(Entry::volume)
+                    .map(MaybeTyped::to_cow)
+                })
+            }
             NumberVariable::CitationNumber => panic!("processor must resolve this"),
             NumberVariable::CollectionNumber => {
                 self.get_collection().and_then(Entry::volume).map(MaybeTyped::to_cow)
@@ -145,7 +151,7 @@ impl EntryLike for Entry {
             NumberVariable::Issue => self.map(|e| e.issue()).map(MaybeTyped::to_cow),
             NumberVariable::Locator => panic!("processor must resolve this"),
             NumberVariable::Number => {
-                return self.serial_number().and_then(|s| s.0.get("serial")).map(|s| {
+                self.serial_number().and_then(|s| s.get("serial")).map(|s| {
                     Numeric::from_str(s)
                         .map(|n| MaybeTyped::Typed(Cow::Owned(n)))
                         .unwrap_or_else(|_| MaybeTyped::String(s.to_owned()))
@@ -161,10 +167,12 @@ impl EntryLike for Entry {
             NumberVariable::PageFirst => self
                 .page_range()
                 .and_then(|r| match r {
-                    MaybeTyped::Typed(r) => r.range(),
+                    MaybeTyped::Typed(r) => {
+                        r.range().map(|r| r.start).or_else(|| r.single_number())
+                    }
                     MaybeTyped::String(_) => None,
                 })
-                .map(|r| MaybeTyped::Typed(Cow::Owned(Numeric::from(r.start)))),
+                .map(|first| MaybeTyped::Typed(Cow::Owned(Numeric::from(first)))),
             NumberVariable::PartNumber => self
                 .bound_select(
                     &select!(
@@ -176,7 +184,7 @@ impl EntryLike for Entry {
                 .map(MaybeTyped::to_cow),
             NumberVariable::PrintingNumber => self
                 .map(|e| e.serial_number())
-                .and_then(|s| s.0.get("printing"))
+                .and_then(|s| s.get("printing"))
                 .map(|s| {
                     Numeric::from_str(s)
                         .map(|n| MaybeTyped::Typed(Cow::Owned(n)))
@@ -187,7 +195,7 @@ impl EntryLike for Entry {
             NumberVariable::Version => self
                 .bound_select(&select!(("e":Repository)), "e")
                 .and_then(Entry::serial_number)
-                .and_then(|s| s.0.get("version"))
+                .and_then(|s| s.get("version"))
                 .map(|s| {
                     Numeric::from_str(s)
                         .map(|n| MaybeTyped::Typed(Cow::Owned(n)))
@@ -255,13 +263,27 @@ impl EntryLike for Entry {
                 entry.doi().map(|d| Cow::Owned(StringChunk::verbatim(d).into()))
             }
             StandardVariable::Event | StandardVariable::EventTitle => entry
-                .bound_select(&select!(* > ("p":(Exhibition | Conference | Misc))), "p")
-                .and_then(Entry::title)
+                .map(|e| e.conference())
+                .or_else(|| {
+                    entry
+                        .bound_select(
+                            &select!(* > ("p":(Exhibition | Conference | Misc))),
+                            "p",
+                        )
+                        .and_then(Entry::title)
+                })
                 .map(|f| f.select(form))
                 .map(Cow::Borrowed),
             StandardVariable::EventPlace => entry
-                .bound_select(&select!(* > ("p":(Exhibition | Conference | Misc))), "p")
-                .and_then(Entry::location)
+                .map(|e| e.event_location())
+                .or_else(|| {
+                    entry
+                        .bound_select(
+                            &select!(* > ("p":(Exhibition | Conference | Misc))),
+                            "p",
+                        )
+                        .and_then(Entry::location)
+                })
                 .map(|f| f.select(form))
                 .map(Cow::Borrowed),
             StandardVariable::Genre => None,
@@ -277,10 +299,10 @@ impl EntryLike for Entry {
                 .map(|e| e.language())
                 .map(|l| Cow::Owned(StringChunk::normal(csl_language(l)).into())),
             StandardVariable::License => None,
-            StandardVariable::Medium => None,
-            StandardVariable::Note => {
-                entry.note().map(|f| f.select(form)).map(Cow::Borrowed)
+            StandardVariable::Medium => {
+                entry.medium().map(|f| f.select(form)).map(Cow::Borrowed)
             }
+            StandardVariable::Note => join_list(entry.notes()?, form),
             StandardVariable::OriginalPublisher => entry
                 .get_original()
                 .and_then(|e| e.publisher())
@@ -303,10 +325,9 @@ impl EntryLike for Entry {
             StandardVariable::PMID => {
                 entry.pmid().map(|d| Cow::Owned(StringChunk::verbatim(d).into()))
             }
-            StandardVariable::Publisher => entry
-                .map(|e| e.publisher())
-                .map(|f| f.select(form))
-                .map(Cow::Borrowed),
+            StandardVariable::Publisher => {
+                entry.map(|e| e.publishers()).and_then(|p| join_list(p, form))
+            }
             StandardVariable::PublisherPlace => entry
                 .map(|e| if e.publisher().is_some() { Some(e) } else { None })
                 .and_then(|e| e.location())
@@ -321,7 +342,9 @@ impl EntryLike for Entry {
                 .and_then(Entry::title)
                 .map(|f| f.select(form))
                 .map(Cow::Borrowed),
-            StandardVariable::Status => None,
+            StandardVariable::Status => {
+                entry.status().map(|s| Cow::Owned(s.to_string().into()))
+            }
             StandardVariable::Title => entry
                 .title()
                 .map(|f| f.select(LongShortForm::Short))
@@ -351,11 +374,15 @@ impl EntryLike for Entry {
 
     fn resolve_date_variable(&self, variable: DateVariable) -> Option<Cow<'_, Date>> {
         Some(Cow::Borrowed(match variable {
-            DateVariable::Accessed => self.url_any().and_then(|u| u.visit_date.as_ref()),
+            DateVariable::Accessed => self.accessed_any(),
             DateVariable::AvailableDate => None,
-            DateVariable::EventDate => self
-                .bound_select(&select!(* > ("p":(Exhibition | Conference | Misc))), "p")
-                .and_then(Entry::date),
+            DateVariable::EventDate => self.map(|e| e.event_date()).or_else(|| {
+                self.bound_select(
+                    &select!(* > ("p":(Exhibition | Conference | Misc))),
+                    "p",
+                )
+                .and_then(Entry::date)
+            }),
             DateVariable::Issued => self.date_any(),
             DateVariable::OriginalDate => self.get_original().and_then(|e| e.date()),
             DateVariable::Submitted => None,
@@ -367,7 +394,7 @@ impl EntryLike for Entry {
         variable: taxonomy::NameVariable,
     ) -> Vec<Cow<'_, Person>> {
         match variable {
-            NameVariable::Author => self.authors().map(|a| a.iter().collect()),
+            NameVariable::Author => Some(self.affiliated_with_role(PersonRole::Author)),
             NameVariable::Chair => self
                 .bound_select(
                     &select!(
@@ -409,7 +436,23 @@ impl EntryLike for Entry {
                     "p",
                 )
                 .map(|e| e.affiliated_with_role(PersonRole::Director)),
-            NameVariable::Editor => self.editors().map(|a| a.iter().collect()),
+            // Contributions to books are edited by the editors of the book.
+            NameVariable::Editor => self
+                .editors()
+                .or_else(|| {
+                    self.get_container()
+                        .filter(|c| {
+                            matches!(
+                                c.entry_type(),
+                                EntryType::Book
+                                    | EntryType::Anthology
+                                    | EntryType::Proceedings
+                                    | EntryType::Reference
+                            )
+                        })
+                        .and_then(Entry::editors)
+                })
+                .map(|a| a.iter().collect()),
             NameVariable::EditorialDirector => None,
             NameVariable::EditorTranslator => {
                 let translator = self.affiliated_with_role(PersonRole::Translator);
@@ -643,6 +686,25 @@ impl EntryLike for Entry {
         }
     }
 
+    fn is_name_list_truncated(&self, variable: NameVariable) -> bool {
+        variable == NameVariable::Author && self.authors_truncated() == Some(&true)
+    }
+
+    fn is_english_variable(&self, variable: StandardVariable) -> Option<bool> {
+        let title = match variable {
+            StandardVariable::Title | StandardVariable::TitleShort => self.title(),
+            StandardVariable::ContainerTitle | StandardVariable::ContainerTitleShort => {
+                self.get_container().and_then(Entry::title)
+            }
+            StandardVariable::CollectionTitle => {
+                self.get_collection().and_then(Entry::title)
+            }
+            _ => None,
+        };
+
+        title?.language.as_ref().map(|l| l.language.as_str() == "en")
+    }
+
     fn is_english(&self) -> Option<bool> {
         self.language().map(|l| l.language.as_str() == "en")
     }
@@ -699,6 +761,7 @@ impl EntryLike for citationberg::json::Item {
                             suffix: None,
                             given_name: None,
                             alias: None,
+                            orcid: None,
                         },
                         csl_json::NameValue::Item(csl_json::NameItem {
                             family,
@@ -734,6 +797,7 @@ impl EntryLike for citationberg::json::Item {
                             suffix: suffix.clone(),
                             given_name: given.clone(),
                             alias: None,
+                            orcid: None,
                         },
                     })
                 })
@@ -771,6 +835,14 @@ impl EntryLike for citationberg::json::Item {
         Kind::from_str(&string) == Ok(kind)
     }
 
+    fn is_name_list_truncated(&self, _: NameVariable) -> bool {
+        false
+    }
+
+    fn is_english_variable(&self, _: StandardVariable) -> Option<bool> {
+        None
+    }
+
     fn is_english(&self) -> Option<bool> {
         self.0
             .get("language")
@@ -793,6 +865,26 @@ pub(super) fn csl_language(lang_id: &LanguageIdentifier) -> String {
     buf
 }
 
+/// Join a list of formattable strings with semicolons.
+fn join_list(
+    list: &[FormatString],
+    form: LongShortForm,
+) -> Option<Cow<'_, ChunkedString>> {
+    match list {
+        [item] => Some(Cow::Borrowed(item.select(form))),
+        items => {
+            let mut joined = ChunkedString::new();
+            for (i, item) in items.iter().enumerate() {
+                if i > 0 {
+                    joined.push_str("; ", ChunkKind::Normal);
+                }
+                joined.extend(item.select(form).clone());
+            }
+            (!joined.is_empty()).then_some(Cow::Owned(joined))
+        }
+    }
+}
+
 // A function that takes a usize value and returns a String
 fn letter(val: u8) -> String {
     let mut result = String::with_capacity(1);
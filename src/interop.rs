@@ -54,6 +54,7 @@ impl From<&tex::Person> for Person {
             prefix: optional(&person.prefix),
             suffix: optional(&person.suffix),
             alias: None,
+            orcid: None,
         }
     }
 }
@@ -95,7 +96,7 @@ impl From<&[Spanned<Chunk>]> for ChunkedString {
 
 impl From<&[Spanned<Chunk>]> for FormatString {
     fn from(chunks: &[Spanned<Chunk>]) -> Self {
-        Self { value: chunks.into(), short: None }
+        Self { value: chunks.into(), short: None, language: None }
     }
 }
 
@@ -435,12 +436,15 @@ impl TryFrom<&tex::Entry> for Entry {
             }
         }
 
-        if let Some(publisher) = map_res(entry.publisher())?.map(|pubs| comma_list(&pubs))
-        {
+        if let Some(publishers) = map_res(entry.publisher())?.map(|pubs| {
+            pubs.iter()
+                .map(|p| comma_list(std::slice::from_ref(p)))
+                .collect::<Vec<_>>()
+        }) {
             if let Some(parent) = book(&mut item, parent) {
-                parent.set_publisher(publisher);
+                parent.set_publishers(publishers);
             } else {
-                item.set_publisher(publisher);
+                item.set_publishers(publishers);
             }
         }
 
@@ -519,7 +523,7 @@ impl TryFrom<&tex::Entry> for Entry {
             .or_else(|| entry.addendum().ok())
             .map(|d| d.format_verbatim())
         {
-            if item.note.is_none() {
+            if item.notes.is_none() {
                 item.set_note(note.into());
             }
         }
@@ -565,5 +569,5 @@ fn comma_list(items: &[Vec<Spanned<Chunk>>]) -> FormatString {
         value.extend(chunked);
     }
 
-    FormatString { value, short: None }
+    FormatString { value, short: None, language: None }
 }
@@ -3,6 +3,11 @@
 #[cfg(feature = "biblatex")]
 use biblatex::{Bibliography, TypeError};
 
+use std::collections::BTreeMap;
+
+use thiserror::Error;
+
+use crate::types::PersonRole;
 use crate::{Entry, Library};
 
 /// Parse a bibliography from a YAML string.
@@ -22,13 +27,593 @@ use crate::{Entry, Library};
 /// let bib = from_yaml_str(yaml).unwrap();
 /// assert_eq!(bib.nth(0).unwrap().date().unwrap().year, 2014);
 /// ```
+///
+/// Entries without a `date` that specify a `year` and optionally a `month`
+/// and `day` get these merged into their `date`, like with the default
+/// [`LoadOptions`].
 pub fn from_yaml_str(s: &str) -> Result<Library, serde_yaml::Error> {
-    serde_yaml::from_str(s)
+    let mut value: serde_yaml::Value = serde_yaml::from_str(strip_bom(s))?;
+    let mut merged = false;
+    if let serde_yaml::Value::Mapping(entries) = &mut value {
+        for entry in entries.values_mut() {
+            merged |= merge_entry_date_parts(entry);
+        }
+    }
+
+    if merged {
+        parse_yaml(&serde_yaml::to_string(&value)?)
+    } else {
+        parse_yaml(s)
+    }
+}
+
+/// Parse a bibliography from a YAML string without any preprocessing.
+fn parse_yaml(s: &str) -> Result<Library, serde_yaml::Error> {
+    serde_yaml::from_str(strip_bom(s))
+}
+
+/// Remove the byte order mark that some editors put at the start of UTF-8
+/// files. The YAML parser would otherwise fail on the first key.
+fn strip_bom(s: &str) -> &str {
+    s.strip_prefix('\u{feff}').unwrap_or(s)
+}
+
+/// Options for [`from_yaml_str_with_options`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LoadOptions {
+    /// Return an error for fields that are not part of the format instead
+    /// of silently ignoring them. Helps to catch typos like `titel`.
+    pub strict_fields: bool,
+    /// Return an error for formattable string fields like `title` that are
+    /// empty or only contain whitespace.
+    pub strict_strings: bool,
+    /// Field names from other formats to rewrite to their canonical name
+    /// before the entries are parsed. Maps from the alias to the canonical
+    /// field. If `None`, [`DEFAULT_FIELD_ALIASES`] are used.
+    pub field_aliases: Option<BTreeMap<String, String>>,
+    /// Lowercase all entry keys so that they can be looked up
+    /// case-insensitively. Keys that only differ in case result in an error.
+    pub canonical_keys: bool,
+    /// Return an error for roles of `affiliated` persons that are not part of
+    /// the format instead of keeping them as [`PersonRole::Unknown`]. Helps to
+    /// catch typos like `transltor`.
+    pub strict_roles: bool,
+    /// Merge separate `year`, `month`, and `day` fields into the `date` of
+    /// entries that have none. On by default.
+    pub merge_date_parts: bool,
+}
+
+impl Default for LoadOptions {
+    fn default() -> Self {
+        Self {
+            strict_fields: false,
+            strict_strings: false,
+            field_aliases: None,
+            canonical_keys: false,
+            strict_roles: false,
+            merge_date_parts: true,
+        }
+    }
+}
+
+/// The field aliases applied by [`from_yaml_str_with_options`] unless
+/// [`LoadOptions::field_aliases`] is set.
+pub const DEFAULT_FIELD_ALIASES: &[(&str, &str)] =
+    &[("year", "date"), ("pages", "page-range")];
+
+/// Parse a bibliography from a YAML string with the given options.
+///
+/// Fields named like one of the [field
+/// aliases](LoadOptions::field_aliases) are renamed to their canonical name
+/// unless the entry already has the canonical field. If
+/// [`LoadOptions::merge_date_parts`] is set, entries without a `date` (or a
+/// field aliased to it) that specify a `year` and optionally a `month` and
+/// `day` get these merged into their `date`. Parts that can not be merged are
+/// left alone, or are an error with [`LoadOptions::strict_fields`].
+///
+/// ```
+/// use hayagriva::io::{from_yaml_str_with_options, LoadOptions};
+///
+/// let yaml = "key:\n    type: Book\n    titel: Typo\n";
+/// let options = LoadOptions { strict_fields: true, ..Default::default() };
+/// assert!(from_yaml_str_with_options(yaml, &options).is_err());
+/// ```
+pub fn from_yaml_str_with_options(
+    s: &str,
+    options: &LoadOptions,
+) -> Result<Library, YamlBibliographyError> {
+    let aliases: BTreeMap<&str, &str> = match &options.field_aliases {
+        Some(aliases) => aliases.iter().map(|(a, c)| (a.as_str(), c.as_str())).collect(),
+        None => DEFAULT_FIELD_ALIASES.iter().copied().collect(),
+    };
+
+    let mut value: serde_yaml::Value = serde_yaml::from_str(strip_bom(s))?;
+    let mut renamed = false;
+    if let serde_yaml::Value::Mapping(entries) = &mut value {
+        if options.canonical_keys {
+            renamed |= canonicalize_keys(entries)?;
+        }
+
+        for (key, entry) in entries.iter_mut() {
+            let key = key.as_str().unwrap_or_default();
+            renamed |= normalize_fields(key, entry, &aliases, options)?;
+            if options.strict_fields {
+                check_fields(key, entry)?;
+            }
+        }
+    }
+
+    // Only parse the rewritten tree if necessary so that untouched input is
+    // parsed exactly as it was written.
+    let mut library = if renamed {
+        parse_yaml(&serde_yaml::to_string(&value)?)?
+    } else {
+        parse_yaml(s)?
+    };
+    if options.strict_roles {
+        for (key, entry) in library.iter().map(|e| (e.key(), e)) {
+            if let Some(role) = unknown_role(entry) {
+                return Err(YamlBibliographyError::UnknownRole {
+                    key: key.to_string(),
+                    role: role.to_string(),
+                });
+            }
+        }
+    }
+
+    if options.strict_strings {
+        for (key, entry) in library.0.iter_mut() {
+            let mut empty = None;
+            entry.visit_format_strings_mut(|field, value| {
+                if empty.is_none() && value.is_empty() {
+                    empty = Some(field.to_string());
+                }
+            });
+
+            if let Some(field) = empty {
+                return Err(YamlBibliographyError::EmptyField {
+                    key: key.clone(),
+                    field,
+                });
+            }
+        }
+    }
+
+    Ok(library)
+}
+
+/// Find an unknown role of affiliated persons in an entry or its parents.
+fn unknown_role(entry: &Entry) -> Option<&str> {
+    let own = entry.affiliated().into_iter().flatten().find_map(|p| match &p.role {
+        PersonRole::Unknown(role) => Some(role.as_str()),
+        _ => None,
+    });
+
+    own.or_else(|| entry.parents().iter().find_map(unknown_role))
+}
+
+/// Lowercase the keys of all entries. Returns whether any key was changed.
+fn canonicalize_keys(
+    entries: &mut serde_yaml::Mapping,
+) -> Result<bool, YamlBibliographyError> {
+    let mut renamed = false;
+    let mut canonical = serde_yaml::Mapping::with_capacity(entries.len());
+    for (key, entry) in std::mem::take(entries) {
+        let key = match key {
+            serde_yaml::Value::String(key) => {
+                let lower = key.to_lowercase();
+                renamed |= lower != key;
+                serde_yaml::Value::String(lower)
+            }
+            key => key,
+        };
+
+        if canonical.contains_key(&key) {
+            return Err(YamlBibliographyError::DuplicateKey {
+                key: key.as_str().unwrap_or_default().to_string(),
+            });
+        }
+
+        canonical.insert(key, entry);
+    }
+
+    *entries = canonical;
+    Ok(renamed)
+}
+
+/// Merge date components if requested and rename aliased fields of an entry
+/// and its parents to their canonical names. Returns whether any field was
+/// changed.
+fn normalize_fields(
+    key: &str,
+    entry: &mut serde_yaml::Value,
+    aliases: &BTreeMap<&str, &str>,
+    options: &LoadOptions,
+) -> Result<bool, YamlBibliographyError> {
+    let serde_yaml::Value::Mapping(fields) = entry else { return Ok(false) };
+
+    // A field aliased to the date takes precedence like the date itself.
+    let aliased_date = aliases.iter().any(|(&alias, &canonical)| {
+        canonical == "date" && alias != "year" && fields.contains_key(alias)
+    });
+    let mut renamed = false;
+    if options.merge_date_parts && !aliased_date {
+        let (merged, invalid) = merge_date_parts(fields);
+        if let Some(field) = invalid.filter(|_| options.strict_fields) {
+            return Err(YamlBibliographyError::InvalidDatePart {
+                key: key.to_string(),
+                field: field.to_string(),
+            });
+        }
+        renamed = merged;
+    }
+
+    for (&alias, &canonical) in aliases {
+        if fields.contains_key(alias) && !fields.contains_key(canonical) {
+            let value = fields.remove(alias).unwrap();
+            fields.insert(canonical.into(), value);
+            renamed = true;
+        }
+    }
+
+    match fields.get_mut("parent") {
+        Some(serde_yaml::Value::Sequence(parents)) => {
+            for parent in parents {
+                renamed |= normalize_fields(key, parent, aliases, options)?;
+            }
+        }
+        Some(parent) => renamed |= normalize_fields(key, parent, aliases, options)?,
+        None => {}
+    }
+
+    Ok(renamed)
+}
+
+/// Merge the date parts of an entry and its parents, leaving parts alone
+/// that can not be merged. Returns whether any fields were merged.
+fn merge_entry_date_parts(entry: &mut serde_yaml::Value) -> bool {
+    let serde_yaml::Value::Mapping(fields) = entry else { return false };
+    let mut merged = merge_date_parts(fields).0;
+    match fields.get_mut("parent") {
+        Some(serde_yaml::Value::Sequence(parents)) => {
+            for parent in parents {
+                merged |= merge_entry_date_parts(parent);
+            }
+        }
+        Some(parent) => merged |= merge_entry_date_parts(parent),
+        None => {}
+    }
+
+    merged
+}
+
+/// Replace separate `year`, `month`, and `day` fields with a `date` field if
+/// there is none yet. The month may be a number or an English month name. A
+/// day is only merged together with a month. Parts that can not be merged
+/// are kept as they are. Returns whether the fields were merged and the first
+/// part that could not be merged.
+fn merge_date_parts(fields: &mut serde_yaml::Mapping) -> (bool, Option<&'static str>) {
+    const MONTHS: [&str; 12] = [
+        "january",
+        "february",
+        "march",
+        "april",
+        "may",
+        "june",
+        "july",
+        "august",
+        "september",
+        "october",
+        "november",
+        "december",
+    ];
+
+    fn number(value: &serde_yaml::Value) -> Option<i64> {
+        match value {
+            serde_yaml::Value::Number(n) => n.as_i64(),
+            serde_yaml::Value::String(s) => s.trim().parse().ok(),
+            _ => None,
+        }
+    }
+
+    if fields.contains_key("date") {
+        return (false, None);
+    }
+
+    let Some(year) = fields.get("year").and_then(number) else { return (false, None) };
+    let mut invalid = None;
+    let month = fields.get("month").and_then(|value| {
+        let name = value.as_str().map(|s| s.trim().to_lowercase());
+        let month = number(value).or_else(|| {
+            let name = name.filter(|n| n.len() >= 3)?;
+            let index = MONTHS.iter().position(|m| m.starts_with(name.as_str()))?;
+            Some(index as i64 + 1)
+        });
+
+        let month = month.filter(|m| (1..=12).contains(m));
+        if month.is_none() {
+            invalid = Some("month");
+        }
+        month
+    });
+
+    let day = fields.get("day").and_then(|value| {
+        let day = number(value).filter(|d| (1..=31).contains(d) && month.is_some());
+        if day.is_none() {
+            invalid = invalid.or(Some("day"));
+        }
+        day
+    });
+
+    let mut date = format!("{:04}", year);
+    fields.remove("year");
+    if let Some(month) = month {
+        date += &format!("-{:02}", month);
+        fields.remove("month");
+    }
+    if let Some(day) = day {
+        date += &format!("-{:02}", day);
+        fields.remove("day");
+    }
+
+    fields.insert("date".into(), date.into());
+    (true, invalid)
+}
+
+/// Check that an entry and its parents only contain known fields.
+fn check_fields(
+    key: &str,
+    entry: &serde_yaml::Value,
+) -> Result<(), YamlBibliographyError> {
+    let serde_yaml::Value::Mapping(fields) = entry else { return Ok(()) };
+    for (field, value) in fields {
+        let Some(field) = field.as_str() else { continue };
+        if !Entry::is_known_field(field) {
+            return Err(YamlBibliographyError::UnknownField {
+                key: key.to_string(),
+                field: field.to_string(),
+            });
+        }
+
+        if field == "parent" {
+            match value {
+                serde_yaml::Value::Sequence(parents) => {
+                    for parent in parents {
+                        check_fields(key, parent)?;
+                    }
+                }
+                parent => check_fields(key, parent)?,
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Errors that may occur when parsing a YAML bibliography with options.
+#[derive(Debug, Error)]
+pub enum YamlBibliographyError {
+    /// The YAML was malformed or did not match the format.
+    #[error("yaml parse error: {0}")]
+    Yaml(#[from] serde_yaml::Error),
+    /// An entry contained a field that is not part of the format.
+    #[error("unknown field `{field}` in entry `{key}`")]
+    UnknownField {
+        /// The key of the offending entry.
+        key: String,
+        /// The name of the unknown field.
+        field: String,
+    },
+    /// A formattable string field of an entry was empty.
+    #[error("empty field `{field}` in entry `{key}`")]
+    EmptyField {
+        /// The key of the offending entry.
+        key: String,
+        /// The name of the empty field.
+        field: String,
+    },
+    /// Persons of an entry had a role that is not part of the format.
+    #[error("unknown role `{role}` in entry `{key}`")]
+    UnknownRole {
+        /// The key of the offending entry.
+        key: String,
+        /// The unknown role.
+        role: String,
+    },
+    /// Two entries had the same key after it was lowercased because of
+    /// [`LoadOptions::canonical_keys`].
+    #[error("multiple entries with key `{key}` when ignoring case")]
+    DuplicateKey {
+        /// The lowercased key.
+        key: String,
+    },
+    /// A separate `month` or `day` field of an entry could not be merged into
+    /// its date while [`LoadOptions::strict_fields`] was set.
+    #[error("invalid date part `{field}` in entry `{key}`")]
+    InvalidDatePart {
+        /// The key of the offending entry.
+        key: String,
+        /// The name of the invalid field.
+        field: String,
+    },
+}
+
+/// Errors that may occur when reading a YAML bibliography from a file.
+#[derive(Debug, Error)]
+pub enum LoadError {
+    /// The file could not be read or was not valid UTF-8.
+    #[error("failed to read file: {0}")]
+    Io(#[source] std::io::Error),
+    /// The YAML was malformed.
+    #[error("yaml parse error: {0}")]
+    Yaml(#[source] serde_yaml::Error),
+}
+
+/// Parse a bibliography from a gzip-compressed YAML file.
+///
+/// The bytes are decompressed and then handed to [`from_yaml_str`].
+#[cfg(feature = "gzip")]
+pub fn from_yaml_gz(bytes: &[u8]) -> Result<Library, GzipYamlError> {
+    use std::io::Read;
+
+    let mut yaml = String::new();
+    flate2::read::GzDecoder::new(bytes)
+        .read_to_string(&mut yaml)
+        .map_err(GzipYamlError::Decompress)?;
+
+    from_yaml_str(&yaml).map_err(GzipYamlError::Yaml)
+}
+
+/// Errors that may occur when parsing a gzip-compressed YAML file.
+#[cfg(feature = "gzip")]
+#[derive(Debug, Error)]
+pub enum GzipYamlError {
+    /// The data could not be decompressed or was not valid UTF-8.
+    #[error("gzip decompression error: {0}")]
+    Decompress(#[source] std::io::Error),
+    /// The decompressed YAML was malformed.
+    #[error("yaml parse error: {0}")]
+    Yaml(#[source] serde_yaml::Error),
 }
 
 /// Serialize a bibliography to a YAML string.
 pub fn to_yaml_str(entries: &Library) -> Result<String, serde_yaml::Error> {
-    serde_yaml::to_string(&entries)
+    let mut buf = Vec::new();
+    write_yaml(&mut buf, entries)?;
+    Ok(String::from_utf8(buf).expect("yaml output is valid utf-8"))
+}
+
+/// Serialize a bibliography as YAML into a writer. The entries are emitted
+/// one after another without building the whole document in memory first.
+pub fn write_yaml(
+    writer: impl std::io::Write,
+    entries: &Library,
+) -> Result<(), serde_yaml::Error> {
+    serde_yaml::to_writer(writer, &entries)
+}
+
+/// The formats a bibliography can be written in with [`export`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ExportFormat {
+    /// YAML, as written by [`to_yaml_str`].
+    Yaml,
+    /// Hayagriva's native JSON schema, as written by [`to_json_str`].
+    #[cfg(feature = "json")]
+    Json,
+}
+
+/// Serialize a bibliography in the given format.
+///
+/// ```
+/// use hayagriva::io::{export, from_yaml_str, ExportFormat};
+///
+/// let bib = from_yaml_str("key:\n    type: Book\n    title: Title\n").unwrap();
+/// let yaml = export(&bib, ExportFormat::Yaml).unwrap();
+/// assert_eq!(from_yaml_str(&yaml).unwrap(), bib);
+/// ```
+pub fn export(entries: &Library, format: ExportFormat) -> Result<String, ExportError> {
+    match format {
+        ExportFormat::Yaml => to_yaml_str(entries).map_err(ExportError::Yaml),
+        #[cfg(feature = "json")]
+        ExportFormat::Json => to_json_str(entries).map_err(ExportError::Json),
+    }
+}
+
+/// Errors that may occur when serializing a bibliography with [`export`].
+#[derive(Debug, Error)]
+#[non_exhaustive]
+pub enum ExportError {
+    /// The bibliography could not be serialized to YAML.
+    #[error("yaml serialization error: {0}")]
+    Yaml(#[source] serde_yaml::Error),
+    /// The bibliography could not be serialized to JSON.
+    #[cfg(feature = "json")]
+    #[error("json serialization error: {0}")]
+    Json(#[source] serde_json::Error),
+}
+
+/// The version of the native JSON schema written by [`to_json_str`].
+#[cfg(feature = "json")]
+pub const JSON_SCHEMA_VERSION: u32 = 1;
+
+/// Serialize a bibliography to a JSON string in Hayagriva's native schema.
+///
+/// Unlike CSL-JSON, this keeps all data of the entries. The output is an
+/// object with a `schema-version` field holding [`JSON_SCHEMA_VERSION`] and
+/// an `entries` field that maps keys to entries, structured just like in the
+/// YAML format.
+#[cfg(feature = "json")]
+pub fn to_json_str(entries: &Library) -> Result<String, serde_json::Error> {
+    let mut buf = Vec::new();
+    write_json(&mut buf, entries)?;
+    Ok(String::from_utf8(buf).expect("json output is valid utf-8"))
+}
+
+/// Serialize a bibliography as JSON in Hayagriva's native schema into a
+/// writer, like [`to_json_str`]. The entries are emitted one after another
+/// without building the whole document in memory first.
+#[cfg(feature = "json")]
+pub fn write_json(
+    writer: impl std::io::Write,
+    entries: &Library,
+) -> Result<(), serde_json::Error> {
+    #[derive(serde::Serialize)]
+    struct JsonLibrary<'a> {
+        #[serde(rename = "schema-version")]
+        schema_version: u32,
+        entries: &'a Library,
+    }
+
+    serde_json::to_writer(
+        writer,
+        &JsonLibrary { schema_version: JSON_SCHEMA_VERSION, entries },
+    )
+}
+
+/// Parse a bibliography from a JSON string in Hayagriva's native schema, as
+/// written by [`to_json_str`].
+///
+/// The `schema-version` field must be present and match
+/// [`JSON_SCHEMA_VERSION`].
+#[cfg(feature = "json")]
+pub fn from_json_str(s: &str) -> Result<Library, JsonError> {
+    #[derive(serde::Deserialize)]
+    struct JsonVersion {
+        #[serde(rename = "schema-version")]
+        schema_version: Option<u32>,
+    }
+
+    #[derive(serde::Deserialize)]
+    struct JsonLibrary {
+        entries: Library,
+    }
+
+    let version: JsonVersion = serde_json::from_str(s).map_err(JsonError::Json)?;
+    match version.schema_version {
+        Some(JSON_SCHEMA_VERSION) => {}
+        Some(version) => return Err(JsonError::UnsupportedVersion(version)),
+        None => return Err(JsonError::MissingVersion),
+    }
+
+    let library: JsonLibrary = serde_json::from_str(s).map_err(JsonError::Json)?;
+    Ok(library.entries)
+}
+
+/// Errors that may occur when parsing a bibliography in the native JSON
+/// schema.
+#[cfg(feature = "json")]
+#[derive(Debug, Error)]
+pub enum JsonError {
+    /// The JSON was malformed or did not match the schema.
+    #[error("json parse error: {0}")]
+    Json(#[source] serde_json::Error),
+    /// The `schema-version` field is missing.
+    #[error("missing schema-version field")]
+    MissingVersion,
+    /// The `schema-version` is not supported by this version of Hayagriva.
+    #[error("unsupported schema version {0} (expected {JSON_SCHEMA_VERSION})")]
+    UnsupportedVersion(u32),
 }
 
 /// Errors that may occur when parsing a BibLaTeX file.
@@ -86,6 +671,309 @@ mod tests {
     use super::*;
     use std::fs;
 
+    #[test]
+    fn byte_order_mark() {
+        let contents = fs::read_to_string("tests/data/basic.yml").unwrap();
+        let expected = from_yaml_str(&contents).unwrap();
+        let with_bom = format!("\u{feff}{}", contents);
+        assert_eq!(from_yaml_str(&with_bom).unwrap(), expected);
+        assert_eq!(
+            from_yaml_str_with_options(&with_bom, &LoadOptions::default()).unwrap(),
+            expected
+        );
+
+        let crlf = with_bom.replace('\n', "\r\n");
+        assert_eq!(from_yaml_str(&crlf).unwrap(), expected);
+    }
+
+    #[test]
+    fn strict_fields() {
+        let strict = LoadOptions { strict_fields: true, ..Default::default() };
+        let contents = fs::read_to_string("tests/data/basic.yml").unwrap();
+        assert_eq!(
+            from_yaml_str_with_options(&contents, &strict).unwrap(),
+            from_yaml_str(&contents).unwrap()
+        );
+
+        let yaml = r#"
+typo:
+    type: Article
+    title: Fine
+    parent:
+        type: Periodical
+        titel: Broken
+"#;
+
+        let lenient = from_yaml_str_with_options(yaml, &LoadOptions::default()).unwrap();
+        assert_eq!(lenient.get("typo").unwrap().parents()[0].title(), None);
+
+        match from_yaml_str_with_options(yaml, &strict) {
+            Err(YamlBibliographyError::UnknownField { key, field }) => {
+                assert_eq!(key, "typo");
+                assert_eq!(field, "titel");
+            }
+            other => panic!("expected unknown field error, got {:?}", other),
+        }
+
+        let alias = "key:\n    type: Web\n    retrieved: 2020\n";
+        assert!(from_yaml_str_with_options(alias, &strict).is_ok());
+    }
+
+    #[test]
+    fn field_aliases() {
+        let yaml = r#"
+aliased:
+    type: Article
+    year: 1961
+    pages: 454-477
+    parent:
+        type: Periodical
+        year: 1960
+canonical:
+    type: Article
+    year: 2000
+    date: 1961
+"#;
+
+        let entries = from_yaml_str_with_options(yaml, &LoadOptions::default()).unwrap();
+        let aliased = entries.get("aliased").unwrap();
+        assert_eq!(aliased.date().unwrap().year, 1961);
+        assert_eq!(aliased.page_range().unwrap().to_string(), "454–477");
+        assert_eq!(aliased.parents()[0].date().unwrap().year, 1960);
+        assert_eq!(entries.get("canonical").unwrap().date().unwrap().year, 1961);
+
+        let strict = LoadOptions { strict_fields: true, ..Default::default() };
+        assert!(from_yaml_str_with_options(yaml, &strict).is_err());
+        assert!(from_yaml_str_with_options(
+            &yaml[..yaml.find("canonical").unwrap()],
+            &strict
+        )
+        .is_ok());
+
+        let custom = LoadOptions {
+            field_aliases: Some(BTreeMap::from([("jahr".into(), "date".into())])),
+            ..Default::default()
+        };
+        let entries = from_yaml_str_with_options(
+            "a:\n    type: Book\n    jahr: 1999\n    year: 1\n",
+            &custom,
+        )
+        .unwrap();
+        assert_eq!(entries.get("a").unwrap().date().unwrap().year, 1999);
+
+        let contents = fs::read_to_string("tests/data/basic.yml").unwrap();
+        assert_eq!(
+            from_yaml_str_with_options(&contents, &custom).unwrap(),
+            from_yaml_str(&contents).unwrap()
+        );
+    }
+
+    #[test]
+    fn date_parts() {
+        let yaml = r#"
+month:
+    type: Article
+    year: 1961
+    month: 5
+named:
+    type: Article
+    year: 1961
+    month: Sep
+    day: 12
+year:
+    type: Article
+    year: 1961
+"#;
+
+        let strict = LoadOptions { strict_fields: true, ..Default::default() };
+        let entries = from_yaml_str_with_options(yaml, &strict).unwrap();
+
+        let date = entries.get("month").unwrap().date().unwrap();
+        assert_eq!((date.year, date.month, date.day), (1961, Some(4), None));
+        let date = entries.get("named").unwrap().date().unwrap();
+        assert_eq!((date.year, date.month, date.day), (1961, Some(8), Some(11)));
+        let date = entries.get("year").unwrap().date().unwrap();
+        assert_eq!((date.year, date.month, date.day), (1961, None, None));
+
+        for (parts, field, month) in [
+            ("month: Maybe", "month", None),
+            ("month: 13", "month", None),
+            ("month: 5\n    day: 32", "day", Some(4)),
+            ("day: 12", "day", None),
+        ] {
+            let yaml =
+                format!("invalid:\n    type: Article\n    year: 1961\n    {parts}\n");
+            let err = from_yaml_str_with_options(&yaml, &strict);
+            assert!(
+                matches!(&err, Err(YamlBibliographyError::InvalidDatePart { field: f, .. }) if f == field),
+                "{parts}"
+            );
+
+            // Without strict fields, the invalid part is left alone.
+            let entries = from_yaml_str(&yaml).unwrap();
+            let date = entries.get("invalid").unwrap().date().unwrap();
+            assert_eq!((date.year, date.month, date.day), (1961, month, None), "{parts}");
+        }
+
+        // The plain loader and custom aliases merge the parts as well.
+        assert_eq!(from_yaml_str(yaml).unwrap(), entries);
+        let custom = LoadOptions {
+            field_aliases: Some(BTreeMap::from([("jahr".into(), "date".into())])),
+            ..Default::default()
+        };
+        assert_eq!(from_yaml_str_with_options(yaml, &custom).unwrap(), entries);
+
+        let unmerged = LoadOptions { merge_date_parts: false, ..custom };
+        let entries = from_yaml_str_with_options(yaml, &unmerged).unwrap();
+        assert_eq!(entries.get("month").unwrap().date(), None);
+    }
+
+    #[test]
+    fn strict_roles() {
+        let yaml = r#"
+typo:
+    type: Book
+    parent:
+        type: Anthology
+        affiliated:
+            - role: transltor
+              names: Smith, John
+            - role: illustrator
+              names: Poe, Edgar
+"#;
+
+        let lenient = from_yaml_str_with_options(yaml, &LoadOptions::default()).unwrap();
+        let affiliated = lenient.get("typo").unwrap().parents()[0].affiliated().unwrap();
+        assert_eq!(affiliated[0].role, PersonRole::Unknown("transltor".into()));
+        assert_eq!(affiliated[1].role, PersonRole::Illustrator);
+        assert_eq!(from_yaml_str(&to_yaml_str(&lenient).unwrap()).unwrap(), lenient);
+
+        let strict = LoadOptions { strict_roles: true, ..Default::default() };
+        match from_yaml_str_with_options(yaml, &strict) {
+            Err(YamlBibliographyError::UnknownRole { key, role }) => {
+                assert_eq!(key, "typo");
+                assert_eq!(role, "transltor");
+            }
+            other => panic!("expected unknown role error, got {:?}", other),
+        }
+
+        let fixed = yaml.replace("transltor", "translator");
+        assert!(from_yaml_str_with_options(&fixed, &strict).is_ok());
+    }
+
+    #[test]
+    fn canonical_keys() {
+        let yaml = r#"
+Smith20:
+    type: Book
+    title: Upper
+smith20:
+    type: Book
+    title: Lower
+"#;
+
+        let entries = from_yaml_str_with_options(yaml, &LoadOptions::default()).unwrap();
+        assert_eq!(entries.len(), 2);
+        assert!(entries.get("Smith20").is_some());
+
+        let canonical = LoadOptions { canonical_keys: true, ..Default::default() };
+        match from_yaml_str_with_options(yaml, &canonical) {
+            Err(YamlBibliographyError::DuplicateKey { key }) => {
+                assert_eq!(key, "smith20")
+            }
+            other => panic!("expected duplicate key error, got {:?}", other),
+        }
+
+        let yaml = &yaml[..yaml.find("smith20").unwrap()];
+        let entries = from_yaml_str_with_options(yaml, &canonical).unwrap();
+        let entry = entries.get("smith20").unwrap();
+        assert_eq!(entry.key(), "smith20");
+        assert_eq!(entry.title().unwrap().value.to_str(), "Upper");
+        assert!(entries.get("Smith20").is_none());
+    }
+
+    #[test]
+    fn strict_strings() {
+        let yaml = "blank:\n    type: Book\n    title: \"  \"\n";
+        let lenient = from_yaml_str_with_options(yaml, &LoadOptions::default()).unwrap();
+        assert!(lenient.get("blank").unwrap().title().unwrap().is_empty());
+
+        let strict = LoadOptions { strict_strings: true, ..Default::default() };
+        match from_yaml_str_with_options(yaml, &strict) {
+            Err(YamlBibliographyError::EmptyField { key, field }) => {
+                assert_eq!(key, "blank");
+                assert_eq!(field, "title");
+            }
+            other => panic!("expected empty field error, got {:?}", other),
+        }
+
+        let contents = fs::read_to_string("tests/data/basic.yml").unwrap();
+        assert!(from_yaml_str_with_options(&contents, &strict).is_ok());
+    }
+
+    #[test]
+    #[cfg(feature = "json")]
+    fn export_formats() {
+        let contents = fs::read_to_string("tests/data/basic.yml").unwrap();
+        let entries = from_yaml_str(&contents).unwrap();
+
+        let yaml = export(&entries, ExportFormat::Yaml).unwrap();
+        assert_eq!(yaml, to_yaml_str(&entries).unwrap());
+        assert_eq!(from_yaml_str(&yaml).unwrap(), entries);
+
+        let json = export(&entries, ExportFormat::Json).unwrap();
+        assert_eq!(json, to_json_str(&entries).unwrap());
+        assert_eq!(from_json_str(&json).unwrap(), entries);
+    }
+
+    #[test]
+    fn write_to_buffer() {
+        let contents = fs::read_to_string("tests/data/basic.yml").unwrap();
+        let entries = from_yaml_str(&contents).unwrap();
+
+        let mut buf: Vec<u8> = Vec::new();
+        write_yaml(&mut buf, &entries).unwrap();
+        assert_eq!(
+            String::from_utf8(buf).unwrap(),
+            serde_yaml::to_string(&entries).unwrap()
+        );
+
+        #[cfg(feature = "json")]
+        {
+            let mut buf: Vec<u8> = Vec::new();
+            write_json(&mut buf, &entries).unwrap();
+            assert_eq!(String::from_utf8(buf).unwrap(), to_json_str(&entries).unwrap());
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "json")]
+    fn json() {
+        let contents = fs::read_to_string("tests/data/basic.yml").unwrap();
+        let entries =
+            from_json_str(&to_json_str(&from_yaml_str(&contents).unwrap()).unwrap())
+                .unwrap();
+
+        let json = to_json_str(&entries).unwrap();
+        let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(value["schema-version"], JSON_SCHEMA_VERSION);
+        assert_eq!(value["entries"]["zygos"]["type"], "article");
+        assert_eq!(from_json_str(&json).unwrap(), entries);
+
+        assert!(matches!(
+            from_json_str(r#"{"entries": {}}"#),
+            Err(JsonError::MissingVersion)
+        ));
+        assert!(matches!(
+            from_json_str(r#"{"schema-version": 99, "entries": {}}"#),
+            Err(JsonError::UnsupportedVersion(99))
+        ));
+        assert!(matches!(
+            from_json_str(r#"{"schema-version": 1, "entries": {"a": {}}}"#),
+            Err(JsonError::Json(_))
+        ));
+    }
+
     #[test]
     fn roundtrip() {
         let contents = fs::read_to_string("tests/data/basic.yml").unwrap();
@@ -101,4 +989,20 @@ mod tests {
             assert_eq!(match_e, &entry);
         }
     }
+
+    #[test]
+    #[cfg(feature = "gzip")]
+    fn gzip() {
+        use std::io::Write;
+
+        let contents = fs::read_to_string("tests/data/basic.yml").unwrap();
+        let mut encoder =
+            flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::default());
+        encoder.write_all(contents.as_bytes()).unwrap();
+        let compressed = encoder.finish().unwrap();
+
+        let entries = from_yaml_gz(&compressed).unwrap();
+        assert_eq!(entries, from_yaml_str(&contents).unwrap());
+        assert!(from_yaml_gz(contents.as_bytes()).is_err());
+    }
 }
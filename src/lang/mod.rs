@@ -945,6 +945,23 @@ mod tests {
         assert_eq!("Comparative Study of Self-Reporting Students' Performance", title);
     }
 
+    #[test]
+    fn case_keeps_separators() {
+        let source =
+            "the  well-known case—a study:  of self-reporting, (mostly) in the U.S.  ";
+        let title = Case::from(TitleCase::new()).transform(source);
+        assert_eq!(
+            title,
+            "The  Well-Known Case—a Study:  Of Self-Reporting, (Mostly) in the U.S."
+        );
+
+        let sentence = Case::from(SentenceCase::new()).transform(source);
+        assert_eq!(
+            sentence,
+            "The  well-known case—a study:  Of self-reporting, (mostly) in the U.S."
+        );
+    }
+
     #[test]
     fn sentence_case() {
         let case: Case = SentenceCase::new().into();
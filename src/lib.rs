@@ -147,28 +147,34 @@ pub mod io;
 pub mod lang;
 pub mod types;
 mod util;
+mod validate;
 
-use std::collections::BTreeMap;
+use std::collections::HashMap;
+use std::path::Path;
 
 #[cfg(feature = "archive")]
 pub use crate::csl::archive;
 pub use citationberg;
 pub use csl::{
-    standalone_citation, BibliographyDriver, BibliographyRequest, Brackets,
-    BufWriteFormat, CitationItem, CitationRequest, CitePurpose, Elem, ElemChild,
-    ElemChildren, ElemMeta, Formatted, Formatting, LocatorPayload, Rendered,
+    standalone_citation, AnonymousPolicy, BibliographyDriver, BibliographyRequest,
+    Brackets, BufWriteFormat, CitationItem, CitationRequest, CitePurpose, Elem,
+    ElemChild, ElemChildren, ElemMeta, Formatted, Formatting, LocatorPayload, Rendered,
     RenderedBibliography, RenderedCitation, SpecificLocator,
 };
 pub use selectors::{Selector, SelectorError};
+pub use validate::ValidationWarning;
 
 use indexmap::IndexMap;
 use paste::paste;
 use serde::{de::Visitor, Deserialize, Serialize};
+use thiserror::Error;
 use types::*;
 use unic_langid::LanguageIdentifier;
 use util::{
-    deserialize_one_or_many_opt, serialize_one_or_many, serialize_one_or_many_opt,
-    OneOrMany,
+    deserialize_affiliated_opt, deserialize_locator_opt, deserialize_one_or_many_opt,
+    deserialize_persons_opt, deserialize_semicolon_list_opt, deserialize_tags_opt,
+    deserialize_urls_opt, serialize_one_or_many, serialize_one_or_many_opt,
+    serialize_semicolon_list_opt, OneOrMany, TitleWithTranslation,
 };
 
 /// A collection of bibliographic entries.
@@ -196,6 +202,15 @@ impl Library {
         self.0.values()
     }
 
+    /// Get an iterator over mutable references to the entries in the library.
+    ///
+    /// Changing the key of an entry through this iterator is not supported
+    /// because the library would still file it under its old key. Use
+    /// [`Library::rename`] instead.
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut Entry> {
+        self.0.values_mut()
+    }
+
     /// Get an iterator over the keys in the library.
     pub fn keys(&self) -> impl Iterator<Item = &str> {
         self.0.keys().map(|k| k.as_str())
@@ -203,7 +218,59 @@ impl Library {
 
     /// Remove an entry from the library.
     pub fn remove(&mut self, key: &str) -> Option<Entry> {
-        self.0.remove(key)
+        self.0.swap_remove(key)
+    }
+
+    /// Change the key of an entry while keeping its position in the library.
+    pub fn rename(&mut self, old: &str, new: &str) -> Result<(), RenameError> {
+        if !self.0.contains_key(old) {
+            return Err(RenameError::Missing(old.to_string()));
+        }
+        if old == new {
+            return Ok(());
+        }
+        if self.0.contains_key(new) {
+            return Err(RenameError::Collision(new.to_string()));
+        }
+
+        let (index, _, mut entry) = self.0.swap_remove_full(old).unwrap();
+        entry.rename_key(new);
+        self.0.insert(new.to_string(), entry);
+        self.0.swap_indices(index, self.0.len() - 1);
+        Ok(())
+    }
+
+    /// Read and parse a YAML bibliography file.
+    pub fn from_yaml_file(path: impl AsRef<Path>) -> Result<Self, io::LoadError> {
+        let yaml = std::fs::read_to_string(path).map_err(io::LoadError::Io)?;
+        io::from_yaml_str(&yaml).map_err(io::LoadError::Yaml)
+    }
+
+    /// Add all entries of another library. Returns the keys that were present
+    /// in both libraries, which are resolved according to the policy.
+    pub fn extend_from(
+        &mut self,
+        other: Library,
+        on_conflict: ConflictPolicy,
+    ) -> Vec<String> {
+        let mut conflicts = Vec::new();
+        for (key, entry) in other.0 {
+            match self.0.get_mut(&key) {
+                Some(existing) => {
+                    match on_conflict {
+                        ConflictPolicy::Skip => {}
+                        ConflictPolicy::Overwrite => *existing = entry,
+                        ConflictPolicy::Merge => existing.merge(entry),
+                    }
+                    conflicts.push(key);
+                }
+                None => {
+                    self.0.insert(key, entry);
+                }
+            }
+        }
+
+        conflicts
     }
 
     /// Get the length of the library.
@@ -216,10 +283,148 @@ impl Library {
         self.0.is_empty()
     }
 
+    /// Count the fields of all entries in the library, as returned by
+    /// [`Entry::field_count`].
+    pub fn total_fields(&self) -> usize {
+        self.iter().map(Entry::field_count).sum()
+    }
+
     /// Get the nth entry in the library.
     pub fn nth(&self, n: usize) -> Option<&Entry> {
         self.0.get_index(n).map(|(_, v)| v)
     }
+
+    /// The combined runtime of the video and audio entries in the library.
+    /// Runtimes that are not given as a duration are skipped.
+    pub fn total_runtime(&self) -> Duration {
+        self.iter()
+            .filter(|e| matches!(e.entry_type, EntryType::Video | EntryType::Audio))
+            .filter_map(|e| match e.runtime()? {
+                MaybeTyped::Typed(runtime) => Some(*runtime),
+                MaybeTyped::String(_) => None,
+            })
+            .sum()
+    }
+
+    /// Get the entries in which a person is named as an author, an editor, or
+    /// another contributor. Persons are matched with [`Person::same_as`].
+    pub fn find_by_author(&self, person: &Person) -> Vec<&Entry> {
+        self.iter()
+            .filter(|entry| {
+                entry.editors().unwrap_or_default().iter().any(|p| p.same_as(person))
+                    || entry.contributors().into_iter().any(|(_, p)| p.same_as(person))
+            })
+            .collect()
+    }
+
+    /// Group the entries by their type. The groups are ordered by the first
+    /// occurrence of their type and keep the order of their entries.
+    pub fn group_by_type(&self) -> IndexMap<EntryType, Vec<&Entry>> {
+        let mut groups: IndexMap<EntryType, Vec<&Entry>> = IndexMap::new();
+        for entry in self.iter() {
+            groups.entry(*entry.entry_type()).or_default().push(entry);
+        }
+
+        groups
+    }
+
+    /// Get the entries tagged with a collection name, in library order.
+    pub fn in_collection(&self, name: &str) -> Vec<&Entry> {
+        self.iter()
+            .filter(|entry| {
+                entry.tags().is_some_and(|tags| tags.iter().any(|t| t == name))
+            })
+            .collect()
+    }
+
+    /// Create a new library by applying a function to every entry. The
+    /// entries are filed under the keys of the returned entries and keep their
+    /// order.
+    pub fn map_entries(&self, f: impl Fn(&Entry) -> Entry) -> Library {
+        self.iter().map(f).collect()
+    }
+
+    /// Find groups of entries that likely describe the same item because they
+    /// share a DOI or have the same type, title, year, and parent title.
+    /// Titles are compared ignoring case and punctuation. The groups and their
+    /// keys are in library order.
+    pub fn find_duplicates(&self) -> Vec<Vec<&str>> {
+        fn root(groups: &[usize], mut i: usize) -> usize {
+            while groups[i] != i {
+                i = groups[i];
+            }
+            i
+        }
+
+        let mut groups: Vec<usize> = (0..self.len()).collect();
+        let mut seen: HashMap<String, usize> = HashMap::new();
+        for (i, entry) in self.iter().enumerate() {
+            let doi = entry.doi().map(|doi| format!("doi:{}", doi.trim().to_lowercase()));
+            let title = entry.normalized_title().map(|title| {
+                let parent = entry.parents().first().and_then(Entry::normalized_title);
+                let year = entry.date().map(|d| d.year);
+                format!("title:{}:{:?}:{:?}:{:?}", title, entry.entry_type, parent, year)
+            });
+
+            for signature in [doi, title].into_iter().flatten() {
+                let other = *seen.entry(signature).or_insert(i);
+                let (a, b) = (root(&groups, other), root(&groups, i));
+                groups[a.max(b)] = a.min(b);
+            }
+        }
+
+        let mut duplicates: IndexMap<usize, Vec<&str>> = IndexMap::new();
+        for (i, key) in self.keys().enumerate() {
+            duplicates.entry(root(&groups, i)).or_default().push(key);
+        }
+
+        duplicates.into_values().filter(|group| group.len() > 1).collect()
+    }
+
+    /// Merge each group of [duplicates](Self::find_duplicates) into its first
+    /// entry with [`Entry::merge`] and remove the other entries. Returns the
+    /// keys of the removed entries.
+    pub fn dedup_merge(&mut self) -> Vec<String> {
+        let groups: Vec<Vec<String>> = self
+            .find_duplicates()
+            .into_iter()
+            .map(|group| group.into_iter().map(ToString::to_string).collect())
+            .collect();
+
+        let mut removed = Vec::new();
+        for group in groups {
+            let (first, rest) = group.split_first().unwrap();
+            for key in rest {
+                let other = self.0.shift_remove(key).unwrap();
+                self.0.get_mut(first).unwrap().merge(other);
+                removed.push(key.clone());
+            }
+        }
+
+        removed
+    }
+}
+
+/// How [`Library::extend_from`] handles keys present in both libraries.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ConflictPolicy {
+    /// Keep the existing entry.
+    Skip,
+    /// Replace the existing entry with the new one.
+    Overwrite,
+    /// Fill missing fields of the existing entry with [`Entry::merge`].
+    Merge,
+}
+
+/// Errors that may occur when renaming an entry in a [`Library`].
+#[derive(Clone, Debug, PartialEq, Eq, Error)]
+pub enum RenameError {
+    /// There is no entry with the given key.
+    #[error("no entry with key `{0}`")]
+    Missing(String),
+    /// Another entry already has the new key.
+    #[error("an entry with key `{0}` already exists")]
+    Collision(String),
 }
 
 impl<'a> IntoIterator for &'a Library {
@@ -288,6 +493,22 @@ macro_rules! entry {
                 &self.key
             }
 
+            /// Change the key of the entry and its parents. Use
+            /// [`Library::rename`] for entries that are part of a library.
+            pub fn rename_key(&mut self, new_key: &str) {
+                self.key = new_key.to_owned();
+                for parent in &mut self.parents {
+                    parent.rename_key(new_key);
+                }
+            }
+
+            /// Clone the entry and its parents under a new key.
+            pub fn clone_with_key(&self, new_key: &str) -> Self {
+                let mut entry = self.clone();
+                entry.rename_key(new_key);
+                entry
+            }
+
             /// Construct a new, empty entry.
             pub fn new(key: &str, entry_type: EntryType) -> Self {
                 Self {
@@ -309,6 +530,66 @@ macro_rules! entry {
                     _ => false,
                 }
             }
+
+            /// Fill the fields that are not set on this entry with the values
+            /// of `other`. The parents of `other` are used if this entry has
+            /// none. The key and type of this entry are kept.
+            pub fn merge(&mut self, other: Entry) {
+                $(
+                    if self.$i.is_none() {
+                        self.$i = other.$i;
+                    }
+                )*
+                if self.parents.is_empty() {
+                    self.parents = other.parents;
+                    for parent in &mut self.parents {
+                        parent.rename_key(&self.key);
+                    }
+                }
+            }
+
+            /// Call `f` with the name and value of every formattable string
+            /// field of this entry and its parents, e.g. to normalize them.
+            pub fn visit_format_strings_mut<F>(&mut self, mut f: F)
+            where
+                F: FnMut(&str, &mut FormatString),
+            {
+                self.visit_format_strings_mut_impl(&mut f);
+            }
+
+            fn visit_format_strings_mut_impl<F>(&mut self, f: &mut F)
+            where
+                F: FnMut(&str, &mut FormatString),
+            {
+                $(
+                    if let Some(value) = self.$i.as_mut() {
+                        value.visit_format_strings_mut(&mut |string| f($s, string));
+                    }
+                )*
+                for parent in &mut self.parents {
+                    parent.visit_format_strings_mut_impl(f);
+                }
+            }
+
+            /// Check whether a field name (or one of its aliases) is
+            /// recognized in the YAML format.
+            pub(crate) fn is_known_field(name: &str) -> bool {
+                let aliases: &[Option<&str>] = &[$($(entry!(@alias $serde),)*)*];
+                matches!(name, "type" | "parent" $(| $s)*)
+                    || aliases.iter().flatten().any(|a| *a == name)
+            }
+
+            /// Whether any field apart from the type and the parents is set.
+            pub(crate) fn has_own_fields(&self) -> bool {
+                false $(|| self.$i.is_some())*
+            }
+
+            /// Count the fields that are set on this entry. The type and the
+            /// parents are not counted and neither are the fields of the
+            /// parents.
+            pub fn field_count(&self) -> usize {
+                0 $(+ usize::from(self.$i.is_some()))*
+            }
         }
 
         /// Getters.
@@ -330,6 +611,11 @@ macro_rules! entry {
 
         /// Setters.
         impl Entry {
+            /// Set the type of the entry.
+            pub fn set_entry_type(&mut self, entry_type: EntryType) {
+                self.entry_type = entry_type;
+            }
+
             /// Set the parents of the entry.
             pub fn set_parents(&mut self, parents: Vec<Entry>) {
                 self.parents = parents;
@@ -339,6 +625,23 @@ macro_rules! entry {
             $(
                 entry!(@set $s => $i : $t);
             )*
+
+            /// Set a formattable string field by its name without trimming
+            /// the value. Returns whether the entry has such a field.
+            pub fn set_raw(&mut self, field: &str, value: FormatString) -> bool {
+                $(
+                    if field == $s {
+                        return match <$t as FieldValue>::from_raw(value) {
+                            Some(value) => {
+                                self.$i = Some(value);
+                                true
+                            }
+                            None => false,
+                        };
+                    }
+                )*
+                false
+            }
         }
 
         /// The library deserialization also handles entries.
@@ -364,7 +667,7 @@ macro_rules! entry {
                         $(#[serde $serde])*
                         #[serde(rename = $s)]
                         #[serde(default)]
-                        $i: Option<$t>,
+                        $i: Option<entry!(@naked $i : $t)>,
                     )*
                 }
 
@@ -387,14 +690,23 @@ macro_rules! entry {
                             .map(|p| p.into_entry(key, Some(entry_type)))
                             .collect();
 
-                        Ok(Entry {
+                        // A title may carry its translation.
+                        let (title, translated) = match self.title {
+                            Some(t) => (Some(t.title), t.translated),
+                            None => (None, None),
+                        };
+                        let title_translated = self.title_translated.or(translated);
+
+                        let mut entry = Entry {
                             key: key.to_owned(),
                             entry_type,
                             parents: parents?,
                             $(
-                                $i: self.$i,
+                                $i: entry!(@unnaked $i, self, title, title_translated),
                             )*
-                        })
+                        };
+                        entry.take_et_al();
+                        Ok(entry)
                     }
                 }
 
@@ -460,6 +772,33 @@ macro_rules! entry {
         $naked.$i = Some(one_or_many.into());
     };
 
+    // The title is deserialized together with an optional translation.
+    (@naked title : $t:ty) => {
+        TitleWithTranslation
+    };
+    (@naked $i:ident : $t:ty) => {
+        $t
+    };
+
+    // Take a field from the naked entry. The title fields are split up before.
+    (@unnaked title, $naked:ident, $title:ident, $translated:ident) => {
+        $title
+    };
+    (@unnaked title_translated, $naked:ident, $title:ident, $translated:ident) => {
+        $translated
+    };
+    (@unnaked $i:ident, $naked:ident, $title:ident, $translated:ident) => {
+        $naked.$i
+    };
+
+    // Extract the alias from a serde attribute, if any.
+    (@alias (alias = $a:literal)) => {
+        Some($a)
+    };
+    (@alias $serde:tt) => {
+        None
+    };
+
     // Getter macro for deref types
     (@get $(#[$docs:meta])+ $s:literal => $i:ident : $t:ty | $d:ty $(,)?) => {
             $(#[$docs])+
@@ -480,32 +819,107 @@ macro_rules! entry {
     (@set $s:literal => $i:ident : $t:ty $(,)?) => {
         paste! {
             #[doc = "Set the `" $s "` field."]
+            ///
+            /// Formattable strings are trimmed and the field is cleared if
+            /// they are empty. Use [`Entry::set_raw`] to store them verbatim.
             pub fn [<set_ $i>](&mut self, $i: $t) {
-                self.$i = Some($i);
+                self.$i = $i.normalize();
             }
         }
     };
 }
 
+/// A type that is stored in an entry field. Every field type needs an
+/// implementation; the provided methods suit fields without formattable
+/// strings.
+trait FieldValue: Sized {
+    /// Prepare a value passed to a setter. Returns `None` if the field should
+    /// be cleared instead.
+    fn normalize(self) -> Option<Self> {
+        Some(self)
+    }
+
+    /// Call `f` with every formattable string in the value.
+    fn visit_format_strings_mut(&mut self, _f: &mut dyn FnMut(&mut FormatString)) {}
+
+    /// Create the value from a single formattable string, if the field holds
+    /// formattable strings.
+    fn from_raw(_value: FormatString) -> Option<Self> {
+        None
+    }
+}
+
+/// Formattable strings are trimmed and discarded if they are empty.
+impl FieldValue for FormatString {
+    fn normalize(mut self) -> Option<Self> {
+        self.trim();
+        (!self.is_empty()).then_some(self)
+    }
+
+    fn visit_format_strings_mut(&mut self, f: &mut dyn FnMut(&mut FormatString)) {
+        f(self);
+    }
+
+    fn from_raw(value: FormatString) -> Option<Self> {
+        Some(value)
+    }
+}
+
+/// Lists of formattable strings drop the empty ones and are discarded if
+/// nothing is left.
+impl FieldValue for Vec<FormatString> {
+    fn normalize(self) -> Option<Self> {
+        let list: Vec<_> = self.into_iter().filter_map(FieldValue::normalize).collect();
+        (!list.is_empty()).then_some(list)
+    }
+
+    fn visit_format_strings_mut(&mut self, f: &mut dyn FnMut(&mut FormatString)) {
+        self.iter_mut().for_each(f);
+    }
+
+    fn from_raw(value: FormatString) -> Option<Self> {
+        Some(vec![value])
+    }
+}
+
+impl FieldValue for bool {}
+impl FieldValue for Date {}
+impl FieldValue for Numeric {}
+impl<T> FieldValue for MaybeTyped<T> {}
+impl FieldValue for PublicationStatus {}
+impl FieldValue for SerialNumber {}
+impl FieldValue for Vec<Person> {}
+impl FieldValue for Vec<PersonsWithRoles> {}
+impl FieldValue for Vec<QualifiedUrl> {}
+impl FieldValue for Vec<String> {}
+impl FieldValue for Vec<LanguageIdentifier> {}
+
 entry! {
     /// Title of the item.
     "title" => title: FormatString,
+    /// Translation of the title of the item.
+    "title-translated" => title_translated: FormatString,
     /// Persons primarily responsible for creating the item.
     #[serde(serialize_with = "serialize_one_or_many_opt")]
-    #[serde(deserialize_with = "deserialize_one_or_many_opt")]
+    #[serde(deserialize_with = "deserialize_persons_opt")]
     "author" => authors: Vec<Person> | [Person],
+    /// Whether the author list is incomplete. The listed authors are then
+    /// always followed by "et al.".
+    "author-truncated" => authors_truncated: bool,
     /// Date at which the item was published.
     "date" => date: Date,
     /// Persons responsible for selecting and revising the content of the item.
     #[serde(serialize_with = "serialize_one_or_many_opt")]
-    #[serde(deserialize_with = "deserialize_one_or_many_opt")]
+    #[serde(deserialize_with = "deserialize_persons_opt")]
     "editor" => editors: Vec<Person> | [Person],
     /// Persons involved in the production of the item that are not authors or editors.
     #[serde(serialize_with = "serialize_one_or_many_opt")]
-    #[serde(deserialize_with = "deserialize_one_or_many_opt")]
+    #[serde(deserialize_with = "deserialize_affiliated_opt")]
     "affiliated" => affiliated: Vec<PersonsWithRoles> | [PersonsWithRoles],
-    /// Publisher of the item.
-    "publisher" => publisher: FormatString,
+    /// Publishers of the item. The first one is the primary publisher.
+    #[serde(serialize_with = "serialize_semicolon_list_opt")]
+    #[serde(deserialize_with = "deserialize_semicolon_list_opt")]
+    "publisher" => publishers: Vec<FormatString> | [FormatString],
     /// Physical location at which the item was published or created.
     "location" => location: FormatString,
     /// Organization at/for which the item was created.
@@ -515,58 +929,145 @@ entry! {
     "issue" => issue: MaybeTyped<Numeric>,
     /// For an item whose parent has multiple volumes/parts/seasons ... of which
     /// this item is one.
+    #[serde(deserialize_with = "deserialize_locator_opt")]
     "volume" => volume: MaybeTyped<Numeric>,
     /// Total number of volumes/parts/seasons ... this item consists of.
     "volume-total" => volume_total: Numeric,
     /// Published version of an item.
     "edition" => edition: MaybeTyped<Numeric>,
     /// The range of pages within the parent this item occupies
+    #[serde(deserialize_with = "deserialize_locator_opt")]
     "page-range" => page_range: MaybeTyped<Numeric>,
+    /// The chapter or range of chapters of the parent this item covers.
+    "chapter" => chapter: MaybeTyped<Numeric>,
     /// The total number of pages the item has.
     "page-total" => page_total: Numeric,
     /// The time range within the parent this item starts and ends at.
     "time-range" => time_range: MaybeTyped<DurationRange>,
     /// The total runtime of the item.
     "runtime" => runtime: MaybeTyped<Duration>,
-    /// Canonical public URL of the item, can have access date.
-    "url" => url: QualifiedUrl,
+    /// Publication status of the item, such as `forthcoming` or `in press`.
+    "status" => status: PublicationStatus,
+    /// Physical or digital medium of the item, such as `streaming video`.
+    "medium" => medium: FormatString,
+    /// Name of the conference at which the item was presented.
+    "conference" => conference: FormatString,
+    /// Date of the event at which the item was presented.
+    "event-date" => event_date: Date,
+    /// Location of the event at which the item was presented.
+    "event-location" => event_location: FormatString,
+    /// Public URLs of the item, each can have an access date. The first one is
+    /// the canonical URL.
+    #[serde(serialize_with = "serialize_one_or_many_opt")]
+    #[serde(deserialize_with = "deserialize_urls_opt")]
+    "url" => urls: Vec<QualifiedUrl> | [QualifiedUrl],
+    /// Date at which the item was accessed, independent of a specific URL.
+    #[serde(alias = "retrieved")]
+    "accessed" => accessed: Date,
     /// Any serial number or version describing the item that is not appropriate
     /// for the fields doi, edition, isbn or issn (may be assigned by the author
     /// of the item; especially useful for preprint archives).
     #[serde(alias = "serial")]
     "serial-number" => serial_number: SerialNumber,
-    /// The language of the item.
-    "language" => language: LanguageIdentifier,
+    /// The languages of the item. The first one is the primary language.
+    #[serde(serialize_with = "serialize_one_or_many_opt")]
+    #[serde(deserialize_with = "deserialize_one_or_many_opt")]
+    "language" => languages: Vec<LanguageIdentifier> | [LanguageIdentifier],
     /// Name of the institution/collection where the item is kept.
     "archive" => archive: FormatString,
     /// Physical location of the institution/collection where the item is kept.
     "archive-location" => archive_location: FormatString,
     /// The call number of the item in the institution/collection.
     "call-number" => call_number: FormatString,
-    /// Additional description to be appended in the bibliographic entry.
-    "note" => note: FormatString,
+    /// Additional descriptions to be appended in the bibliographic entry.
+    #[serde(serialize_with = "serialize_one_or_many_opt")]
+    #[serde(deserialize_with = "deserialize_one_or_many_opt")]
+    "note" => notes: Vec<FormatString> | [FormatString],
+    /// Free-form tags that sort the item into collections.
+    #[serde(alias = "collections")]
+    #[serde(serialize_with = "serialize_one_or_many_opt")]
+    #[serde(deserialize_with = "deserialize_tags_opt")]
+    "tags" => tags: Vec<String> | [String],
 }
 
 impl Entry {
     /// Get and parse the `affiliated` field and only return persons of a given
-    /// [role](PersonRole).
+    /// [role](PersonRole). For [`PersonRole::Author`], the persons of the
+    /// `author` field come first.
     pub(crate) fn affiliated_with_role(&self, role: PersonRole) -> Vec<&Person> {
-        self.affiliated
+        let authors = match role {
+            PersonRole::Author => self.authors.as_deref().unwrap_or_default(),
+            _ => &[],
+        };
+
+        authors
             .iter()
-            .flatten()
-            .filter_map(
-                |PersonsWithRoles { names, role: r }| {
-                    if r == &role {
-                        Some(names)
-                    } else {
-                        None
-                    }
-                },
+            .chain(
+                self.affiliated
+                    .iter()
+                    .flatten()
+                    .filter_map(
+                        |PersonsWithRoles { names, role: r }| {
+                            if r == &role {
+                                Some(names)
+                            } else {
+                                None
+                            }
+                        },
+                    )
+                    .flatten(),
             )
+            .collect()
+    }
+
+    /// Get all persons that contributed to this entry together with their
+    /// role. The persons of the `author` field are reported with
+    /// [`PersonRole::Author`] and come first.
+    pub fn contributors(&self) -> Vec<(PersonRole, &Person)> {
+        self.authors
+            .iter()
             .flatten()
+            .map(|p| (PersonRole::Author, p))
+            .chain(self.affiliated.iter().flatten().flat_map(
+                |PersonsWithRoles { names, role }| {
+                    names.iter().map(move |p| (role.clone(), p))
+                },
+            ))
             .collect()
     }
 
+    /// Get the first author of the entry or, if it has no authors, its first
+    /// editor.
+    pub fn first_author(&self) -> Option<&Person> {
+        self.authors_or_editors().and_then(|persons| persons.first())
+    }
+
+    /// Get the last author of the entry or, if it has no authors, its last
+    /// editor.
+    pub fn last_author(&self) -> Option<&Person> {
+        self.authors_or_editors().and_then(|persons| persons.last())
+    }
+
+    /// Replace a literal "et al." at the end of the author list with the
+    /// `author-truncated` flag.
+    fn take_et_al(&mut self) {
+        let Some(authors) = &mut self.authors else { return };
+        if authors.last().is_some_and(Person::is_et_al) {
+            authors.pop();
+            self.authors_truncated = Some(true);
+            if authors.is_empty() {
+                self.authors = None;
+            }
+        }
+    }
+
+    /// The authors if there are any and the editors otherwise.
+    fn authors_or_editors(&self) -> Option<&[Person]> {
+        self.authors()
+            .filter(|persons| !persons.is_empty())
+            .or_else(|| self.editors())
+    }
+
     /// Get the unconverted value of a certain field from this entry or any of
     /// its parents.
     pub fn map<'a, F, T>(&'a self, mut f: F) -> Option<T>
@@ -597,9 +1098,7 @@ impl Entry {
             // Index parents with the items in path. If, at any level, the index
             // exceeds the number of parents, increment the index at the
             // previous level. If no other level remains, return.
-            let Some(first_path) = path.first() else {
-                return None;
-            };
+            let first_path = path.first()?;
 
             if self.parents.len() <= *first_path {
                 return None;
@@ -625,6 +1124,17 @@ impl Entry {
         }
     }
 
+    /// Get the first parent with the given type.
+    pub fn parent_of_kind(&self, entry_type: EntryType) -> Option<&Self> {
+        self.map_parents(|e| (e.entry_type == entry_type).then_some(e))
+    }
+
+    /// Get an owned copy of the first parent with the given type, e.g. for
+    /// language bindings that cannot hold references into the entry.
+    pub fn parent_of_kind_cloned(&self, entry_type: EntryType) -> Option<Self> {
+        self.parent_of_kind(entry_type).cloned()
+    }
+
     /// Apply a selector and return a bound parent entry or self.
     pub fn bound_select(&self, selector: &Selector, binding: &str) -> Option<&Entry> {
         selector.apply(self).and_then(|map| map.get(binding).copied())
@@ -637,25 +1147,89 @@ impl Entry {
 
     /// Will recursively get an URL off either the entry or any of its ancestors.
     pub fn url_any(&self) -> Option<&QualifiedUrl> {
-        self.map(|e| e.url.as_ref())
+        self.map(|e| e.url())
+    }
+
+    /// Will recursively get the access date off either the entry or any of its
+    /// ancestors. The entry-level `accessed` field takes precedence over the
+    /// visit date of an URL.
+    pub fn accessed_any(&self) -> Option<&Date> {
+        self.map(|e| e.accessed.as_ref())
+            .or_else(|| self.url_any().and_then(|u| u.visit_date.as_ref()))
     }
 
-    /// Retrieve a keyed serial number.
+    /// The total number of volumes. If the `volume-total` field is not set,
+    /// it is derived from the span of a volume range like `1-3`.
+    pub fn total_volumes(&self) -> Option<Numeric> {
+        self.volume_total.clone().or_else(|| match self.volume()? {
+            MaybeTyped::Typed(volume) => {
+                volume.range().map(|range| Numeric::new(range.end - range.start + 1))
+            }
+            MaybeTyped::String(_) => None,
+        })
+    }
+
+    /// The canonical public URL of the item, i.e. the first of its URLs.
+    pub fn url(&self) -> Option<&QualifiedUrl> {
+        self.urls.as_ref().and_then(|urls| urls.first())
+    }
+
+    /// Set the `url` field to a single URL.
+    pub fn set_url(&mut self, url: QualifiedUrl) {
+        self.urls = Some(vec![url]);
+    }
+
+    /// The primary language of the item, i.e. the first of its languages.
+    pub fn language(&self) -> Option<&LanguageIdentifier> {
+        self.languages.as_ref().and_then(|languages| languages.first())
+    }
+
+    /// Set the `language` field to a single language.
+    pub fn set_language(&mut self, language: LanguageIdentifier) {
+        self.languages = Some(vec![language]);
+    }
+
+    /// The first of the item's publishers.
+    pub fn publisher(&self) -> Option<&FormatString> {
+        self.publishers.as_ref().and_then(|publishers| publishers.first())
+    }
+
+    /// Set the `publisher` field to a single publisher.
+    ///
+    /// The publisher is trimmed and the field is cleared if it is empty. Use
+    /// [`Entry::set_raw`] to store it verbatim.
+    pub fn set_publisher(&mut self, publisher: FormatString) {
+        self.publishers = vec![publisher].normalize();
+    }
+
+    /// The first of the item's notes.
+    pub fn note(&self) -> Option<&FormatString> {
+        self.notes.as_ref().and_then(|notes| notes.first())
+    }
+
+    /// Set the `note` field to a single note.
+    ///
+    /// The note is trimmed and the field is cleared if it is empty. Use
+    /// [`Entry::set_raw`] to store it verbatim.
+    pub fn set_note(&mut self, note: FormatString) {
+        self.notes = vec![note].normalize();
+    }
+
+    /// Retrieve the primary keyed serial number.
     pub fn keyed_serial_number(&self, key: &str) -> Option<&str> {
-        self.serial_number
-            .as_ref()
-            .and_then(|s| s.0.get(key).map(|s| s.as_str()))
+        self.serial_number.as_ref().and_then(|s| s.get(key))
+    }
+
+    /// Retrieve all keyed serial numbers for a key.
+    pub fn keyed_serial_numbers(&self, key: &str) -> &[String] {
+        self.serial_number.as_ref().map_or(&[], |s| s.get_all(key))
     }
 
-    /// Set a keyed serial number.
+    /// Set a keyed serial number, replacing all others with the same key.
     pub fn set_keyed_serial_number(&mut self, key: &str, value: String) {
-        if let Some(serials) = &mut self.serial_number {
-            serials.0.insert(key.to_owned(), value);
-        } else {
-            let mut map = BTreeMap::new();
-            map.insert(key.to_owned(), value);
-            self.serial_number = Some(SerialNumber(map));
-        }
+        self.serial_number
+            .get_or_insert_with(SerialNumber::default)
+            .set(key, value);
     }
 
     /// The Digital Object Identifier of the item.
@@ -663,6 +1237,12 @@ impl Entry {
         self.keyed_serial_number("doi")
     }
 
+    /// All Digital Object Identifiers of the item, e.g. of a preprint and the
+    /// version of record. The first one is the primary DOI.
+    pub fn dois(&self) -> &[String] {
+        self.keyed_serial_numbers("doi")
+    }
+
     /// Set the `doi` field.
     pub fn set_doi(&mut self, doi: String) {
         self.set_keyed_serial_number("doi", doi);
@@ -673,6 +1253,11 @@ impl Entry {
         self.keyed_serial_number("isbn")
     }
 
+    /// All ISBNs of the item, e.g. for different formats.
+    pub fn isbns(&self) -> &[String] {
+        self.keyed_serial_numbers("isbn")
+    }
+
     /// Set the `isbn` field.
     pub fn set_isbn(&mut self, isbn: String) {
         self.set_keyed_serial_number("isbn", isbn);
@@ -683,6 +1268,11 @@ impl Entry {
         self.keyed_serial_number("issn")
     }
 
+    /// All ISSNs of the item, e.g. for the print and electronic editions.
+    pub fn issns(&self) -> &[String] {
+        self.keyed_serial_numbers("issn")
+    }
+
     /// Set the `issn` field.
     pub fn set_issn(&mut self, issn: String) {
         self.set_keyed_serial_number("issn", issn);
@@ -836,6 +1426,21 @@ impl Entry {
     pub(crate) fn get_original(&self) -> Option<&Self> {
         self.dfs_parent(EntryType::Original)
     }
+
+    /// The title in lowercase without punctuation and with collapsed
+    /// whitespace, for comparing titles across entries.
+    pub(crate) fn normalized_title(&self) -> Option<String> {
+        let title: String = self
+            .title()?
+            .value
+            .to_str()
+            .chars()
+            .filter(|c| c.is_alphanumeric() || c.is_whitespace())
+            .collect::<String>()
+            .to_lowercase();
+        let words: Vec<_> = title.split_whitespace().collect();
+        (!words.is_empty()).then(|| words.join(" "))
+    }
 }
 
 #[cfg(feature = "biblatex")]
@@ -965,6 +1570,1058 @@ mod tests {
         );
     }
 
+    #[test]
+    fn persons_as_maps() {
+        let yaml = r#"
+knighted:
+    type: Anthology
+    title: Collected Works
+    author:
+        - name: Doyle
+          given-name: Arthur Conan
+          prefix: Sir
+    editor:
+        - name: Pratchett
+          given_name: Terry
+          prefix: Sir
+        - Gaiman, Neil
+"#;
+
+        let entries = from_yaml_str(yaml).unwrap();
+        let entry = entries.get("knighted").unwrap();
+
+        let authors = entry.authors().unwrap();
+        assert_eq!(authors.len(), 1);
+        assert_eq!(authors[0].name, "Doyle");
+        assert_eq!(authors[0].given_name.as_deref(), Some("Arthur Conan"));
+        assert_eq!(authors[0].prefix.as_deref(), Some("Sir"));
+
+        let editors = entry.editors().unwrap();
+        assert_eq!(editors.len(), 2);
+        assert_eq!(editors[0].name, "Pratchett");
+        assert_eq!(editors[0].given_name.as_deref(), Some("Terry"));
+        assert_eq!(editors[0].prefix.as_deref(), Some("Sir"));
+        assert_eq!(editors[1].name, "Gaiman");
+        assert_eq!(editors[1].given_name.as_deref(), Some("Neil"));
+    }
+
+    #[test]
+    fn url_list() {
+        let yaml = r#"
+both:
+    type: Web
+    url:
+        - https://example.com/a
+        - value: https://example.com/b
+          date: 2021-03-04
+single:
+    type: Web
+    url: https://example.com/c
+broken:
+    type: Web
+    url:
+        - https://example.com/a
+        - not a url
+"#;
+
+        let err = from_yaml_str(yaml).unwrap_err();
+        assert!(err.to_string().contains("invalid url at index 1"), "{}", err);
+
+        let yaml = yaml.split("broken:").next().unwrap();
+        let entries = from_yaml_str(yaml).unwrap();
+        let both = entries.get("both").unwrap();
+        let urls = both.urls().unwrap();
+        assert_eq!(urls.len(), 2);
+        assert_eq!(urls[0].value.as_str(), "https://example.com/a");
+        assert_eq!(urls[0].visit_date, None);
+        assert_eq!(urls[1].visit_date, Some(Date::new(2021, Some(3), Some(4)).unwrap()));
+        assert_eq!(both.url(), Some(&urls[0]));
+
+        let single = entries.get("single").unwrap();
+        assert_eq!(single.urls().unwrap().len(), 1);
+        assert_eq!(single.url().unwrap().value.as_str(), "https://example.com/c");
+
+        let yaml = crate::io::to_yaml_str(&entries).unwrap();
+        assert_eq!(from_yaml_str(&yaml).unwrap(), entries);
+    }
+
+    #[test]
+    fn language_list() {
+        let yaml = r#"
+bilingual:
+    type: Book
+    language: [en, fr]
+single:
+    type: Book
+    language: de-CH
+"#;
+
+        let entries = from_yaml_str(yaml).unwrap();
+        let bilingual = entries.get("bilingual").unwrap();
+        let languages = bilingual.languages().unwrap();
+        assert_eq!(languages.len(), 2);
+        assert_eq!(languages[1].language.as_str(), "fr");
+        assert_eq!(bilingual.language(), Some(&languages[0]));
+        assert_eq!(bilingual.language().unwrap().language.as_str(), "en");
+
+        let mut single = entries.get("single").unwrap().clone();
+        assert_eq!(single.languages().unwrap().len(), 1);
+        assert_eq!(single.language().unwrap().to_string(), "de-CH");
+
+        let yaml = crate::io::to_yaml_str(&entries).unwrap();
+        assert_eq!(from_yaml_str(&yaml).unwrap(), entries);
+
+        single.set_language("nl".parse().unwrap());
+        assert_eq!(single.languages().unwrap().len(), 1);
+        assert_eq!(single.language().unwrap().to_string(), "nl");
+    }
+
+    #[test]
+    fn note_list() {
+        let yaml = r#"
+two:
+    type: Misc
+    note:
+        - Reprinted 1990.
+        - value: Translated from the German.
+          short: Transl.
+one:
+    type: Misc
+    note: Unpublished.
+"#;
+
+        let entries = from_yaml_str(yaml).unwrap();
+        let two = entries.get("two").unwrap();
+        let notes = two.notes().unwrap();
+        assert_eq!(notes.len(), 2);
+        assert_eq!(two.note(), Some(&notes[0]));
+        assert_eq!(notes[0].to_string(), "Reprinted 1990.");
+        assert_eq!(notes[1].short.as_ref().unwrap().to_string(), "Transl.");
+
+        let mut one = entries.get("one").unwrap().clone();
+        assert_eq!(one.notes().unwrap().len(), 1);
+        assert_eq!(one.note().unwrap().to_string(), "Unpublished.");
+
+        let yaml = crate::io::to_yaml_str(&entries).unwrap();
+        assert_eq!(from_yaml_str(&yaml).unwrap(), entries);
+
+        one.set_note(FormatString::with_value("Forthcoming."));
+        assert_eq!(one.notes(), Some(&[FormatString::with_value("Forthcoming.")][..]));
+    }
+
+    #[test]
+    fn trimmed_setters() {
+        let mut entry = Entry::new("key", EntryType::Misc);
+        entry.set_note(FormatString::with_value("  "));
+        assert_eq!(entry.notes(), None);
+        assert!(!entry.has("note"));
+
+        entry.set_title(FormatString::with_value("  Title\n"));
+        assert_eq!(entry.title(), Some(&FormatString::with_value("Title")));
+        entry.set_title(FormatString::new());
+        assert_eq!(entry.title(), None);
+
+        entry.set_notes(vec![FormatString::with_value(" a "), FormatString::new()]);
+        assert_eq!(entry.notes(), Some(&[FormatString::with_value("a")][..]));
+
+        assert!(entry.set_raw("note", FormatString::with_value("  ")));
+        assert_eq!(entry.note(), Some(&FormatString::with_value("  ")));
+        assert!(entry.set_raw("title", FormatString::with_value(" Title ")));
+        assert_eq!(entry.title(), Some(&FormatString::with_value(" Title ")));
+        assert!(!entry.set_raw("date", FormatString::with_value("2020")));
+        assert!(!entry.set_raw("titel", FormatString::with_value("Title")));
+    }
+
+    #[test]
+    fn publishers() {
+        let entries = from_yaml_str(
+            r#"
+            split:
+                type: Book
+                publisher: Routledge; Taylor & Francis
+            list:
+                type: Book
+                publisher: [Routledge, Taylor & Francis]
+            single:
+                type: Book
+                publisher: Penguin Books
+            braced:
+                type: Book
+                publisher: "{Routledge; Taylor} Press"
+            "#,
+        )
+        .unwrap();
+
+        let expected = [
+            FormatString::with_value("Routledge"),
+            FormatString::with_value("Taylor & Francis"),
+        ];
+        for key in ["split", "list"] {
+            let entry = entries.get(key).unwrap();
+            assert_eq!(entry.publishers(), Some(&expected[..]));
+            assert_eq!(entry.publisher(), Some(&expected[0]));
+        }
+
+        let single = entries.get("single").unwrap();
+        assert_eq!(single.publishers().unwrap().len(), 1);
+        assert_eq!(single.publisher().unwrap().value.to_str(), "Penguin Books");
+
+        let braced = entries.get("braced").unwrap();
+        assert_eq!(braced.publishers().unwrap().len(), 1);
+        assert_eq!(braced.publisher().unwrap().value.to_str(), "Routledge; Taylor Press");
+
+        let mut entries = entries;
+        let mut semicolon = Entry::new("semicolon", EntryType::Book);
+        semicolon.set_publisher(FormatString::with_value("A; B"));
+        entries.push(&semicolon);
+
+        let yaml = crate::io::to_yaml_str(&entries).unwrap();
+        assert!(yaml.contains("publisher: Penguin Books"));
+        let reloaded = from_yaml_str(&yaml).unwrap();
+        assert_eq!(reloaded, entries);
+        assert_eq!(reloaded.get("semicolon").unwrap().publishers().unwrap().len(), 1);
+    }
+
+    #[test]
+    fn visit_format_strings() {
+        let contents = fs::read_to_string("tests/data/basic.yml").unwrap();
+        let entries = from_yaml_str(&contents).unwrap();
+        let mut entry = entries.get("zygos").unwrap().clone();
+
+        let mut visited = vec![];
+        entry.visit_format_strings_mut(|name, string| {
+            visited.push(name.to_string());
+            let upper = string.value.to_uppercase();
+            string.value = ChunkedString::from(upper);
+        });
+
+        assert_eq!(visited, ["title", "title", "publisher", "location"]);
+        assert_eq!(
+            entry.title().unwrap().to_string(),
+            "ZYGOS: ACHIEVING LOW TAIL LATENCY FOR MICROSECOND-SCALE NETWORKED TASKS"
+        );
+        assert_eq!(
+            entry.parents()[0].location().unwrap().to_string(),
+            "NEW YORK, NY, USA"
+        );
+        assert_eq!(entry.authors(), entries.get("zygos").unwrap().authors());
+    }
+
+    #[test]
+    fn entry_type() {
+        let mut entry = Entry::new("key", EntryType::Book);
+        assert_eq!(entry.key(), "key");
+        assert_eq!(entry.entry_type(), &EntryType::Book);
+
+        entry.set_entry_type(EntryType::Thesis);
+        assert_eq!(entry.entry_type(), &EntryType::Thesis);
+        assert!(crate::select!(Thesis).matches(&entry));
+    }
+
+    #[test]
+    fn semicolon_separated_persons() {
+        let yaml = r#"
+two:
+    type: Book
+    author: Smith, John; Doe, Jane
+    editor: Roe, Richard;
+one:
+    type: Book
+    author: Smith, John
+empty:
+    type: Book
+    author: ""
+    editor: ;
+"#;
+
+        let entries = from_yaml_str(yaml).unwrap();
+        let two = entries.get("two").unwrap();
+        assert_eq!(
+            two.authors().unwrap(),
+            [
+                Person::from_strings(vec!["Smith", "John"]).unwrap(),
+                Person::from_strings(vec!["Doe", "Jane"]).unwrap(),
+            ]
+        );
+        assert_eq!(two.editors().unwrap().len(), 1);
+
+        let one = entries.get("one").unwrap();
+        assert_eq!(
+            one.authors().unwrap(),
+            [Person::from_strings(vec!["Smith", "John"]).unwrap()]
+        );
+
+        let empty = entries.get("empty").unwrap();
+        assert_eq!(empty.authors(), None);
+        assert_eq!(empty.editors(), None);
+    }
+
+    #[test]
+    fn extend_from() {
+        let first = from_yaml_str(
+            r#"
+shared:
+    type: Book
+    title: First
+only-first:
+    type: Web
+"#,
+        )
+        .unwrap();
+        let second = from_yaml_str(
+            r#"
+shared:
+    type: Article
+    title: Second
+    date: 2020
+    parent:
+        type: Periodical
+        title: Journal
+only-second:
+    type: Misc
+"#,
+        )
+        .unwrap();
+
+        let keys =
+            |lib: &Library| lib.keys().map(ToString::to_string).collect::<Vec<_>>();
+
+        let mut skip = first.clone();
+        let conflicts = skip.extend_from(second.clone(), ConflictPolicy::Skip);
+        assert_eq!(conflicts, ["shared"]);
+        assert_eq!(keys(&skip), ["shared", "only-first", "only-second"]);
+        assert_eq!(skip.get("shared"), first.get("shared"));
+
+        let mut overwrite = first.clone();
+        overwrite.extend_from(second.clone(), ConflictPolicy::Overwrite);
+        assert_eq!(keys(&overwrite), ["shared", "only-first", "only-second"]);
+        assert_eq!(overwrite.get("shared"), second.get("shared"));
+
+        let mut merge = first.clone();
+        merge.extend_from(second.clone(), ConflictPolicy::Merge);
+        let shared = merge.get("shared").unwrap();
+        assert_eq!(shared.entry_type(), &EntryType::Book);
+        assert_eq!(shared.title().unwrap().to_string(), "First");
+        assert_eq!(shared.date(), Some(&Date::from_year(2020)));
+        assert_eq!(shared.parents()[0].title().unwrap().to_string(), "Journal");
+        assert_eq!(merge.len(), 3);
+    }
+
+    #[test]
+    fn page_range_labels() {
+        let yaml = r#"
+english:
+    type: Article
+    page-range: pp. 10-20
+    volume: vol. 3
+german:
+    type: Article
+    page-range: S. 5
+"#;
+
+        let entries = from_yaml_str(yaml).unwrap();
+        let english = entries.get("english").unwrap();
+        assert_eq!(
+            english.page_range(),
+            Some(&MaybeTyped::Typed("10-20".parse().unwrap()))
+        );
+        assert_eq!(english.volume().unwrap().to_string(), "vol. 3");
+
+        let german = entries.get("german").unwrap();
+        assert_eq!(german.page_range(), Some(&MaybeTyped::Typed(Numeric::new(5))));
+    }
+
+    #[test]
+    fn chapter() {
+        let yaml = r#"
+single:
+    type: Chapter
+    chapter: 3
+    page-range: 45-67
+range:
+    type: Chapter
+    chapter: 3-4
+"#;
+
+        let entries = from_yaml_str(yaml).unwrap();
+        let single = entries.get("single").unwrap();
+        assert_eq!(single.chapter(), Some(&MaybeTyped::Typed(Numeric::new(3))));
+        assert_eq!(single.page_range().unwrap().to_string(), "45–67");
+
+        let range = entries.get("range").unwrap();
+        let chapter = range.chapter().unwrap();
+        assert_eq!(chapter, &MaybeTyped::Typed("3-4".parse().unwrap()));
+        assert_eq!(chapter.to_string(), "3–4");
+        assert_eq!(range.page_range(), None);
+    }
+
+    #[test]
+    fn parent_of_kind() {
+        let contents = fs::read_to_string("tests/data/basic.yml").unwrap();
+        let entries = from_yaml_str(&contents).unwrap();
+        let mut entry = entries.get("zygos").unwrap().clone();
+
+        let proceedings = entry.parent_of_kind(EntryType::Proceedings).unwrap();
+        assert_eq!(proceedings, &entry.parents()[0]);
+        assert_eq!(entry.parent_of_kind(EntryType::Periodical), None);
+        assert_eq!(entry.parent_of_kind_cloned(EntryType::Periodical), None);
+
+        let mut cloned = entry.parent_of_kind_cloned(EntryType::Proceedings).unwrap();
+        assert_eq!(&cloned, &entry.parents()[0]);
+        cloned.set_title(FormatString::with_value("Changed"));
+        assert_ne!(&cloned, &entry.parents()[0]);
+
+        entry.set_parents(vec![]);
+        assert_eq!(entry.parent_of_kind(EntryType::Proceedings), None);
+        assert_eq!(cloned.title().unwrap().to_string(), "Changed");
+    }
+
+    #[test]
+    fn from_yaml_file() {
+        let entries = Library::from_yaml_file("tests/data/basic.yml").unwrap();
+        let contents = fs::read_to_string("tests/data/basic.yml").unwrap();
+        assert_eq!(entries, from_yaml_str(&contents).unwrap());
+
+        assert!(matches!(
+            Library::from_yaml_file("tests/data/missing.yml"),
+            Err(io::LoadError::Io(_))
+        ));
+        assert!(matches!(
+            Library::from_yaml_file("tests/data/art-history.csl"),
+            Err(io::LoadError::Yaml(_))
+        ));
+    }
+
+    #[test]
+    fn serial_number_lists() {
+        let yaml = r#"
+journal:
+    type: Periodical
+    serial-number:
+        issn: [0028-0836, 1476-4687]
+        isbn: 978-0747551003
+"#;
+
+        let entries = from_yaml_str(yaml).unwrap();
+        let journal = entries.get("journal").unwrap();
+        assert_eq!(journal.issn(), Some("0028-0836"));
+        assert_eq!(journal.issns(), ["0028-0836", "1476-4687"]);
+        assert_eq!(journal.isbn(), Some("978-0747551003"));
+        assert_eq!(journal.isbns(), ["978-0747551003"]);
+        assert!(journal.keyed_serial_numbers("doi").is_empty());
+
+        let yaml = crate::io::to_yaml_str(&entries).unwrap();
+        assert!(yaml.contains("isbn: 978-0747551003"));
+        assert_eq!(from_yaml_str(&yaml).unwrap(), entries);
+
+        let mut journal = journal.clone();
+        journal.set_issn("2412-3129".into());
+        assert_eq!(journal.issns(), ["2412-3129"]);
+
+        let nested =
+            "journal:\n    type: Periodical\n    serial-number:\n        issn: [[1]]\n";
+        assert!(from_yaml_str(nested).is_err());
+    }
+
+    #[test]
+    fn clone_with_key() {
+        let contents = fs::read_to_string("tests/data/basic.yml").unwrap();
+        let entries = from_yaml_str(&contents).unwrap();
+        let original = entries.get("zygos").unwrap();
+
+        let clone = original.clone_with_key("prekas-2017");
+        assert_eq!(clone.key(), "prekas-2017");
+        assert_eq!(clone.parents()[0].key(), "prekas-2017");
+        assert_eq!(clone.title(), original.title());
+        assert_eq!(original.key(), "zygos");
+        assert_eq!(original.parents()[0].key(), "zygos");
+    }
+
+    #[test]
+    fn translated_title() {
+        let entries = from_yaml_str(
+            r#"
+            kafka:
+                type: Book
+                title: ["Die Verwandlung", "The Metamorphosis"]
+            plain:
+                type: Book
+                title: Der Process
+            "#,
+        )
+        .unwrap();
+
+        let kafka = entries.get("kafka").unwrap();
+        assert_eq!(kafka.title().unwrap().value.to_str(), "Die Verwandlung");
+        assert_eq!(kafka.title_translated().unwrap().value.to_str(), "The Metamorphosis");
+        let plain = entries.get("plain").unwrap();
+        assert_eq!(plain.title().unwrap().value.to_str(), "Der Process");
+        assert_eq!(plain.title_translated(), None);
+
+        let yaml = io::to_yaml_str(&entries).unwrap();
+        assert!(yaml.contains("title-translated: The Metamorphosis"));
+        assert_eq!(from_yaml_str(&yaml).unwrap(), entries);
+
+        let err = from_yaml_str(
+            r#"
+            kafka:
+                type: Book
+                title: ["Die Verwandlung", "The Metamorphosis", "La Métamorphose"]
+            "#,
+        );
+        assert!(err.is_err());
+    }
+
+    #[test]
+    fn et_al_author() {
+        let entries = from_yaml_str(
+            r#"
+            listed:
+                type: Article
+                author: ["Smith, John", "et al."]
+            inline:
+                type: Article
+                author: Smith, John; Doe, Jane; et al
+            complete:
+                type: Article
+                author: ["Smith, John", "Al, Et"]
+            "#,
+        )
+        .unwrap();
+
+        let listed = entries.get("listed").unwrap();
+        assert_eq!(listed.authors().unwrap().len(), 1);
+        assert_eq!(listed.authors().unwrap()[0].name, "Smith");
+        assert_eq!(listed.authors_truncated(), Some(&true));
+        let inline = entries.get("inline").unwrap();
+        assert_eq!(inline.authors().unwrap().len(), 2);
+        assert_eq!(inline.authors_truncated(), Some(&true));
+        let complete = entries.get("complete").unwrap();
+        assert_eq!(complete.authors().unwrap().len(), 2);
+        assert_eq!(complete.authors_truncated(), None);
+
+        let yaml = io::to_yaml_str(&entries).unwrap();
+        assert!(yaml.contains("author-truncated: true"));
+        assert_eq!(from_yaml_str(&yaml).unwrap(), entries);
+    }
+
+    #[test]
+    fn total_volumes() {
+        let entries = from_yaml_str(
+            r#"
+            span:
+                type: Book
+                volume: 1-3
+            explicit:
+                type: Book
+                volume: 1-3
+                volume-total: 5
+            single:
+                type: Book
+                volume: 2
+            reversed:
+                type: Book
+                volume: 3-1
+            "#,
+        )
+        .unwrap();
+
+        let total = |key| entries.get(key).unwrap().total_volumes();
+        assert_eq!(total("span"), Some(Numeric::new(3)));
+        assert_eq!(total("explicit"), Some(Numeric::new(5)));
+        assert_eq!(total("single"), None);
+        assert_eq!(total("reversed"), None);
+    }
+
+    #[test]
+    fn total_runtime() {
+        let entries = from_yaml_str(
+            r#"
+            part-1:
+                type: Video
+                runtime: "58:12"
+            part-2:
+                type: Video
+                runtime: "01:01:48"
+            soundtrack:
+                type: Audio
+                runtime: "42:00"
+            script:
+                type: Book
+                runtime: "10:00"
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(entries.total_runtime(), "02:42:00".parse().unwrap());
+        assert_eq!(Library::new().total_runtime(), Duration::default());
+    }
+
+    #[test]
+    fn status() {
+        let entries = from_yaml_str(
+            r#"
+            announced:
+                type: Book
+                status: forthcoming
+            accepted:
+                type: Article
+                status: in press
+            reviewed:
+                type: Article
+                status: under review
+            "#,
+        )
+        .unwrap();
+
+        let status = |key| entries.get(key).unwrap().status();
+        assert_eq!(status("announced"), Some(&PublicationStatus::Forthcoming));
+        assert_eq!(status("accepted"), Some(&PublicationStatus::InPress));
+        assert_eq!(
+            status("reviewed"),
+            Some(&PublicationStatus::Other("under review".into()))
+        );
+        assert_eq!(status("accepted").unwrap().to_string(), "in press");
+
+        let yaml = io::to_yaml_str(&entries).unwrap();
+        assert_eq!(from_yaml_str(&yaml).unwrap(), entries);
+    }
+
+    #[test]
+    fn find_by_author() {
+        let entries = from_yaml_str(
+            r#"
+            monograph:
+                type: Book
+                author: Ostrom, Elinor
+            volume:
+                type: Anthology
+                editor: ["Hess, Charlotte", "Ostrom, E."]
+            translation:
+                type: Book
+                affiliated:
+                    translator: Ostrom, Elinor
+            other:
+                type: Book
+                author: Ostrom, Vincent
+            "#,
+        )
+        .unwrap();
+
+        let person = Person::from_strings(vec!["Ostrom", "Elinor"]).unwrap();
+        let keys: Vec<_> =
+            entries.find_by_author(&person).into_iter().map(Entry::key).collect();
+        assert_eq!(keys, ["monograph", "volume", "translation"]);
+    }
+
+    #[test]
+    fn iter_mut() {
+        let contents = fs::read_to_string("tests/data/basic.yml").unwrap();
+        let mut entries = from_yaml_str(&contents).unwrap();
+
+        for entry in entries.iter_mut() {
+            entry.set_publisher(FormatString::with_value("Hayagriva Press"));
+        }
+
+        assert!(entries.iter().all(|e| {
+            e.publisher().map(|p| p.value.to_str()) == Some("Hayagriva Press".into())
+        }));
+        assert!(entries.get("zygos").unwrap().publisher().is_some());
+    }
+
+    #[test]
+    fn field_count() {
+        let entries = from_yaml_str(
+            r#"
+            a:
+                type: Article
+                title: A
+                author: Doe, Jane
+                date: 2020
+                parent:
+                    type: Periodical
+                    title: Journal
+                    volume: 3
+            b:
+                type: Misc
+            "#,
+        )
+        .unwrap();
+
+        let a = entries.get("a").unwrap();
+        assert_eq!(a.field_count(), 3);
+        assert_eq!(a.parents()[0].field_count(), 2);
+        assert_eq!(entries.get("b").unwrap().field_count(), 0);
+        assert_eq!(entries.total_fields(), 3);
+        assert_eq!(Library::new().total_fields(), 0);
+    }
+
+    #[test]
+    fn rename() {
+        let contents = fs::read_to_string("tests/data/basic.yml").unwrap();
+        let mut entries = from_yaml_str(&contents).unwrap();
+        let keys: Vec<_> = entries.keys().map(ToString::to_string).collect();
+        let original = entries.get("zygos").unwrap().clone();
+
+        entries.rename("zygos", "prekas-2017").unwrap();
+        assert!(entries.get("zygos").is_none());
+        let renamed = entries.get("prekas-2017").unwrap();
+        assert_eq!(renamed.key(), "prekas-2017");
+        assert_eq!(renamed.parents()[0].key(), "prekas-2017");
+        assert_eq!(renamed.title(), original.title());
+
+        let index = keys.iter().position(|k| k == "zygos").unwrap();
+        assert_eq!(entries.nth(index).unwrap().key(), "prekas-2017");
+        assert_eq!(entries.len(), keys.len());
+
+        assert_eq!(
+            entries.rename("zygos", "other"),
+            Err(RenameError::Missing("zygos".into()))
+        );
+        assert_eq!(
+            entries.rename("prekas-2017", &keys[1]),
+            Err(RenameError::Collision(keys[1].clone()))
+        );
+    }
+
+    #[test]
+    fn author_role() {
+        let yaml = r#"
+entry:
+    type: Book
+    title: Example
+    author: ["Doe, Jane", "Roe, Richard"]
+    affiliated:
+        - role: translator
+          names: Smith, John
+        - role: author
+          names: Poe, Edgar
+"#;
+
+        let entries = from_yaml_str(yaml).unwrap();
+        let mut entry = entries.get("entry").unwrap().clone();
+        let names = |persons: Vec<&Person>| -> Vec<String> {
+            persons.into_iter().map(|p| p.name.clone()).collect()
+        };
+
+        assert_eq!(entry.authors().unwrap().len(), 2);
+        assert_eq!(entry.authors().unwrap()[0].name, "Doe");
+        assert_eq!(
+            names(entry.affiliated_with_role(PersonRole::Author)),
+            ["Doe", "Roe", "Poe"]
+        );
+
+        let contributors = entry.contributors();
+        assert_eq!(contributors.len(), 4);
+        assert_eq!(contributors[0].0, PersonRole::Author);
+        assert_eq!(contributors[1].1.name, "Roe");
+        assert_eq!(contributors[2].0, PersonRole::Translator);
+        assert_eq!(
+            contributors[3],
+            (PersonRole::Author, &Person::from_strings(vec!["Poe", "Edgar"]).unwrap())
+        );
+
+        entry.set_authors(vec![Person::from_strings(vec!["Kwan", "Kevin"]).unwrap()]);
+        assert_eq!(entry.authors().unwrap().len(), 1);
+        assert_eq!(
+            names(entry.affiliated_with_role(PersonRole::Author)),
+            ["Kwan", "Poe"]
+        );
+        assert_eq!(entry.contributors()[0].1.name, "Kwan");
+    }
+
+    #[test]
+    fn dedup_merge() {
+        let yaml = r#"
+first:
+    type: Article
+    title: "Magnetic Vortex: A Study"
+    date: 2020
+    author: Doe, Jane
+second:
+    type: Article
+    title: magnetic vortex -- a study
+    date: 2020-05
+    page-range: 10-20
+other:
+    type: Article
+    title: Magnetic Vortex
+    date: 2020
+doi-a:
+    type: Book
+    title: One Title
+    serial-number: { doi: 10.1000/XYZ }
+doi-b:
+    type: Book
+    title: Another Title
+    serial-number: { doi: 10.1000/xyz }
+    publisher: Publisher
+intro-a:
+    type: Chapter
+    title: Introduction
+    date: 2020
+    parent: { type: Book, title: First Book }
+intro-b:
+    type: Chapter
+    title: Introduction
+    date: 2020
+    parent: { type: Book, title: Second Book }
+"#;
+
+        let mut entries = from_yaml_str(yaml).unwrap();
+        assert_eq!(entries.find_duplicates(), [["first", "second"], ["doi-a", "doi-b"]]);
+
+        assert_eq!(entries.dedup_merge(), ["second", "doi-b"]);
+        assert_eq!(
+            entries.keys().collect::<Vec<_>>(),
+            ["first", "other", "doi-a", "intro-a", "intro-b"]
+        );
+
+        let first = entries.get("first").unwrap();
+        assert_eq!(first.title().unwrap().value.to_str(), "Magnetic Vortex: A Study");
+        assert_eq!(first.date().unwrap().month, None);
+        assert_eq!(first.authors().unwrap()[0].name, "Doe");
+        assert_eq!(first.page_range().unwrap().to_string(), "10–20");
+        assert_eq!(
+            entries.get("doi-a").unwrap().publisher().unwrap().value.to_str(),
+            "Publisher"
+        );
+
+        assert!(entries.find_duplicates().is_empty());
+        assert!(entries.dedup_merge().is_empty());
+    }
+
+    #[test]
+    fn map_entries() {
+        let entries = from_yaml_str(
+            r#"
+vortex:
+    type: Article
+    title: Magnetic vortex
+asians:
+    type: Book
+"#,
+        )
+        .unwrap();
+
+        let mapped = entries.map_entries(|entry| {
+            let mut entry = entry.clone();
+            if let Some(title) = entry.title() {
+                let title = title.value.to_uppercase();
+                entry.set_title(FormatString::with_value(title));
+            }
+            entry
+        });
+
+        assert_eq!(mapped.len(), 2);
+        assert_eq!(mapped.keys().collect::<Vec<_>>(), ["vortex", "asians"]);
+        assert_eq!(
+            mapped.get("vortex").unwrap().title().unwrap().value.to_str(),
+            "MAGNETIC VORTEX"
+        );
+        assert_eq!(mapped.get("asians"), entries.get("asians"));
+        assert_eq!(
+            entries.get("vortex").unwrap().title().unwrap().value.to_str(),
+            "Magnetic vortex"
+        );
+    }
+
+    #[test]
+    fn group_by_type() {
+        let yaml = r#"
+vortex:
+    type: Article
+asians:
+    type: Book
+dependence:
+    type: Article
+aeon:
+    type: Blog
+feminism:
+    type: Book
+"#;
+
+        let entries = from_yaml_str(yaml).unwrap();
+        let groups = entries.group_by_type();
+        let keys = |entries: &Vec<&Entry>| -> Vec<String> {
+            entries.iter().map(|e| e.key.clone()).collect()
+        };
+
+        assert_eq!(
+            groups.keys().copied().collect::<Vec<_>>(),
+            [EntryType::Article, EntryType::Book, EntryType::Blog]
+        );
+        assert_eq!(keys(&groups[&EntryType::Article]), ["vortex", "dependence"]);
+        assert_eq!(keys(&groups[&EntryType::Book]), ["asians", "feminism"]);
+        assert_eq!(keys(&groups[&EntryType::Blog]), ["aeon"]);
+        assert!(Library::new().group_by_type().is_empty());
+    }
+
+    #[test]
+    fn in_collection() {
+        let yaml = r#"
+vortex:
+    type: Article
+    tags: physics, fluids
+asians:
+    type: Book
+    collections: [history]
+dependence:
+    type: Article
+    tags:
+        - physics
+        - history
+aeon:
+    type: Blog
+"#;
+
+        let entries = from_yaml_str(yaml).unwrap();
+        let keys = |name| -> Vec<&str> {
+            entries.in_collection(name).iter().map(|e| e.key()).collect()
+        };
+
+        assert_eq!(entries.get("vortex").unwrap().tags().unwrap(), ["physics", "fluids"]);
+        assert_eq!(keys("physics"), ["vortex", "dependence"]);
+        assert_eq!(keys("history"), ["asians", "dependence"]);
+        assert_eq!(keys("fluids"), ["vortex"]);
+        assert!(keys("poetry").is_empty());
+
+        let yaml = io::to_yaml_str(&entries).unwrap();
+        assert_eq!(from_yaml_str(&yaml).unwrap(), entries);
+    }
+
+    #[test]
+    fn affiliated_map() {
+        let yaml = r#"
+map:
+    type: Book
+    affiliated:
+        translator: ["Smith, John", "Miller, Anna"]
+        illustrator: Poe, Edgar
+group:
+    type: Book
+    affiliated:
+        role: translator
+        names: Smith, John
+mixed:
+    type: Book
+    affiliated:
+        role: translator
+        names: Smith, John
+        illustrator: Poe, Edgar
+"#;
+
+        assert!(from_yaml_str(yaml).is_err());
+        let entries = from_yaml_str(yaml.split("mixed:").next().unwrap()).unwrap();
+
+        let map = entries.get("map").unwrap();
+        let affiliated = map.affiliated().unwrap();
+        assert_eq!(affiliated.len(), 2);
+        assert_eq!(affiliated[0].role, PersonRole::Translator);
+        assert_eq!(affiliated[0].names.len(), 2);
+        assert_eq!(affiliated[1].role, PersonRole::Illustrator);
+        assert_eq!(affiliated[1].names[0].name, "Poe");
+
+        let group = entries.get("group").unwrap().affiliated().unwrap();
+        assert_eq!(
+            group,
+            &[PersonsWithRoles::new(
+                vec![Person::from_strings(vec!["Smith", "John"]).unwrap()],
+                PersonRole::Translator
+            )]
+        );
+
+        let yaml = crate::io::to_yaml_str(&entries).unwrap();
+        assert_eq!(from_yaml_str(&yaml).unwrap(), entries);
+    }
+
+    #[test]
+    fn first_and_last_author() {
+        let yaml = r#"
+authored:
+    type: Book
+    author: ["Doe, Jane", "Roe, Richard", "Poe, Edgar"]
+    editor: Smith, John
+edited:
+    type: Anthology
+    editor: ["Smith, John", "Miller, Anna"]
+anonymous:
+    type: Misc
+"#;
+
+        let entries = from_yaml_str(yaml).unwrap();
+        let authored = entries.get("authored").unwrap();
+        assert_eq!(authored.first_author().unwrap().name, "Doe");
+        assert_eq!(authored.last_author().unwrap().name, "Poe");
+
+        let edited = entries.get("edited").unwrap();
+        assert_eq!(edited.first_author().unwrap().name, "Smith");
+        assert_eq!(edited.last_author().unwrap().name, "Miller");
+
+        let anonymous = entries.get("anonymous").unwrap();
+        assert_eq!(anonymous.first_author(), None);
+        assert_eq!(anonymous.last_author(), None);
+    }
+
+    #[test]
+    fn accessed_date() {
+        let yaml = r#"
+both:
+    type: Web
+    title: Example
+    url:
+        value: https://example.com
+        date: 2021-03-04
+    accessed: 2022-05-06
+retrieved:
+    type: Web
+    retrieved: 2020
+"#;
+
+        let mut entries = from_yaml_str(yaml).unwrap();
+        let entry = entries.get("both").unwrap();
+        let visited = entry.url().unwrap().visit_date.unwrap();
+        assert_eq!((visited.year, visited.month), (2021, Some(2)));
+        let accessed = entry.accessed().unwrap();
+        assert_eq!((accessed.year, accessed.month), (2022, Some(4)));
+        assert_eq!(entry.accessed_any(), Some(accessed));
+
+        let mut entry = entry.clone();
+        entry.set_accessed(Date::from_year(2023));
+        assert_eq!(entry.url().unwrap().visit_date, Some(visited));
+        entries.push(&entry);
+
+        assert_eq!(
+            entries.get("retrieved").unwrap().accessed(),
+            Some(&Date::from_year(2020))
+        );
+
+        let yaml = crate::io::to_yaml_str(&entries).unwrap();
+        assert_eq!(from_yaml_str(&yaml).unwrap(), entries);
+    }
+
+    #[test]
+    fn selector_without_parents() {
+        let orphan = Entry::new("orphan", EntryType::Article);
+        let mut child = Entry::new("child", EntryType::Article);
+        child.set_parents(vec![Entry::new("child", EntryType::Periodical)]);
+
+        let empty = Selector::Ancestrage(
+            Box::new(Selector::Wildcard),
+            Box::new(Selector::Multi(vec![])),
+        );
+        assert!(empty.matches(&orphan));
+        assert!(empty.matches(&child));
+
+        let periodical = Selector::parse("article > (periodical & periodical)").unwrap();
+        assert!(!periodical.matches(&orphan));
+        assert!(!periodical.matches(&child));
+        assert!(Selector::parse("article > periodical").unwrap().matches(&child));
+        assert!(!Selector::parse("article > periodical").unwrap().matches(&orphan));
+        assert!(!Selector::parse("article > *").unwrap().matches(&orphan));
+        assert!(Selector::parse("article > !periodical").unwrap().matches(&orphan));
+    }
+
     #[test]
     fn selector_bindings() {
         let contents = fs::read_to_string("tests/data/basic.yml").unwrap();
@@ -115,9 +115,11 @@ pub enum Selector {
     Attr(Box<Self>, Vec<String>),
     /// An alternate selector: `a | b`.
     Alt(Vec<Self>),
-    /// A multi-parent selector: `a & b`.
+    /// A multi-parent selector: `a & b`. Each selector must match a different
+    /// parent. An empty list matches any parents, even none.
     Multi(Vec<Self>),
-    /// An ancestrage selector: `a > b`.
+    /// An ancestrage selector: `a > b`. An entry without parents only matches
+    /// if the right-hand side does not require a parent, e.g. `a > !b`.
     Ancestrage(Box<Self>, Box<Self>),
 }
 
@@ -228,7 +230,7 @@ impl Selector {
             Self::Binding(binding, expr) => {
                 expr.apply_any(entries).map(|(mut bound, es)| {
                     if !es.is_empty() {
-                        bound.insert(binding.to_string(), es.get(0).unwrap());
+                        bound.insert(binding.to_string(), es.first().unwrap());
                     }
                     (bound, vec![])
                 })
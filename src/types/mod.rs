@@ -239,6 +239,19 @@ pub enum EntryType {
 }
 
 impl EntryType {
+    /// The type of parent that entries of this type are expected to have
+    /// because they are usually part of a larger work. For example, an
+    /// article is expected to be part of a periodical and a chapter part of
+    /// a book. Returns `None` for types that usually stand on their own.
+    pub fn default_parent_type(&self) -> Option<Self> {
+        match self {
+            Self::Article | Self::Chapter | Self::Entry | Self::Anthos | Self::Scene => {
+                Some(self.default_parent())
+            }
+            _ => None,
+        }
+    }
+
     /// Entry parents have implicit defaults. This function returns the default
     /// parent for this entry type.
     pub(crate) fn default_parent(&self) -> Self {
@@ -268,6 +281,35 @@ impl FromStr for EntryType {
     }
 }
 
+/// The publication status of an item that is not (or no longer) regularly
+/// published.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+#[serde(rename_all = "kebab-case")]
+pub enum PublicationStatus {
+    /// The item is announced but not yet accepted by a publisher.
+    Forthcoming,
+    /// The item is accepted and will be published.
+    #[serde(alias = "in press")]
+    InPress,
+    /// The item was withdrawn after its publication.
+    Retracted,
+    /// Another status described by the contained string.
+    #[serde(untagged)]
+    Other(String),
+}
+
+impl Display for PublicationStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Forthcoming => "forthcoming",
+            Self::InPress => "in press",
+            Self::Retracted => "retracted",
+            Self::Other(status) => status,
+        })
+    }
+}
+
 /// This error can occur when converting from YAML to a type.
 #[derive(Clone, Debug, Error, PartialEq, Eq)]
 #[allow(missing_docs)]
@@ -298,13 +340,42 @@ pub enum DeserializationError {
 pub enum MaybeTyped<T> {
     /// The typed variant.
     Typed(T),
-    /// The fallback string variant.
+    /// The fallback string variant. Floats that cannot be parsed as the typed
+    /// variant end up here, too.
+    #[serde(deserialize_with = "deserialize_str_or_float")]
     String(String),
 }
 
+/// Deserialize a string, turning floats into their string representation.
+fn deserialize_str_or_float<'de, D>(deserializer: D) -> Result<String, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    use serde::de::{self, Visitor};
+    struct OurVisitor;
+
+    impl<'de> Visitor<'de> for OurVisitor {
+        type Value = String;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            formatter.write_str("a string")
+        }
+
+        fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+            Ok(v.to_string())
+        }
+
+        fn visit_f64<E: de::Error>(self, v: f64) -> Result<Self::Value, E> {
+            Ok(v.to_string())
+        }
+    }
+
+    deserializer.deserialize_any(OurVisitor)
+}
+
 impl<T: ToOwned> MaybeTyped<T> {
     /// Wrap the typed value in a [`Cow`]'s borrowed variant.
-    pub fn to_cow(&self) -> MaybeTyped<Cow<T>> {
+    pub fn to_cow(&self) -> MaybeTyped<Cow<'_, T>> {
         match self {
             MaybeTyped::Typed(t) => MaybeTyped::Typed(Cow::Borrowed(t)),
             MaybeTyped::String(s) => MaybeTyped::String(s.clone()),
@@ -323,7 +394,7 @@ impl<T: Display> Display for MaybeTyped<T> {
 
 impl<T: ToString> MaybeTyped<T> {
     /// Convert the value to a string.
-    pub fn to_str(&self) -> Cow<str> {
+    pub fn to_str(&self) -> Cow<'_, str> {
         match self {
             MaybeTyped::Typed(t) => Cow::Owned(t.to_string()),
             MaybeTyped::String(s) => Cow::Borrowed(s),
@@ -331,6 +402,17 @@ impl<T: ToString> MaybeTyped<T> {
     }
 }
 
+impl MaybeTyped<Numeric> {
+    /// Get the value as a single number. Strings are parsed as integers and
+    /// numerics must not have a prefix, suffix, or multiple values.
+    pub fn as_i64(&self) -> Option<i64> {
+        match self {
+            MaybeTyped::Typed(n) => n.single_number().map(i64::from),
+            MaybeTyped::String(s) => s.trim().parse().ok(),
+        }
+    }
+}
+
 impl<T> MaybeTyped<T>
 where
     T: FromStr,
@@ -410,10 +492,55 @@ impl Display for QualifiedUrl {
 }
 
 /// A set of serial numbers like DOIs, ISBNs, or ISSNs.
-/// Keys should be lowercase.
-#[derive(Clone, Debug, PartialEq, Eq, Serialize, Hash)]
-#[serde(transparent)]
-pub struct SerialNumber(pub BTreeMap<String, String>);
+/// Keys should be lowercase. Each scheme can have multiple numbers, e.g. a
+/// print and an electronic ISSN. The first one is the primary number.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct SerialNumber(BTreeMap<String, Vec<String>>);
+
+impl SerialNumber {
+    /// The primary serial number for a key.
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.0.get(key).and_then(|v| v.first()).map(String::as_str)
+    }
+
+    /// All serial numbers for a key.
+    pub fn get_all(&self, key: &str) -> &[String] {
+        self.0.get(key).map_or(&[], Vec::as_slice)
+    }
+
+    /// Set the serial number for a key, replacing all others with that key.
+    pub fn set(&mut self, key: impl Into<String>, value: impl Into<String>) {
+        self.0.insert(key.into(), vec![value.into()]);
+    }
+
+    /// Iterate over the keys and all their serial numbers.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &[String])> {
+        self.0.iter().map(|(k, v)| (k.as_str(), v.as_slice()))
+    }
+}
+
+impl From<BTreeMap<String, String>> for SerialNumber {
+    fn from(map: BTreeMap<String, String>) -> Self {
+        Self(map.into_iter().map(|(k, v)| (k, vec![v])).collect())
+    }
+}
+
+impl Serialize for SerialNumber {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let mut map = serializer.serialize_map(Some(self.0.len()))?;
+        for (key, values) in &self.0 {
+            if values.len() == 1 {
+                map.serialize_entry(key, &values[0])?;
+            } else {
+                map.serialize_entry(key, values)?;
+            }
+        }
+        map.end()
+    }
+}
 
 impl<'de> Deserialize<'de> for SerialNumber {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
@@ -423,10 +550,17 @@ impl<'de> Deserialize<'de> for SerialNumber {
         #[derive(Deserialize)]
         #[serde(untagged)]
         enum Choice {
-            Map(BTreeMap<String, StringOrNumber>),
+            Map(BTreeMap<String, OneOrMany>),
             Other(StringOrNumber),
         }
 
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum OneOrMany {
+            One(StringOrNumber),
+            Many(Vec<StringOrNumber>),
+        }
+
         #[derive(Deserialize)]
         #[serde(untagged)]
         enum StringOrNumber {
@@ -436,13 +570,13 @@ impl<'de> Deserialize<'de> for SerialNumber {
             Float(f64),
         }
 
-        impl ToString for StringOrNumber {
-            fn to_string(&self) -> String {
+        impl Display for StringOrNumber {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
                 match self {
-                    Self::String(s) => s.clone(),
-                    Self::Number(n) => n.to_string(),
-                    Self::UnsignedNumber(n) => n.to_string(),
-                    Self::Float(f) => f.to_string(),
+                    Self::String(s) => s.fmt(f),
+                    Self::Number(n) => n.fmt(f),
+                    Self::UnsignedNumber(n) => n.fmt(f),
+                    Self::Float(n) => n.fmt(f),
                 }
             }
         }
@@ -450,11 +584,21 @@ impl<'de> Deserialize<'de> for SerialNumber {
         Choice::deserialize(deserializer).map(|choice| match choice {
             Choice::Other(text) => SerialNumber(BTreeMap::from_iter(vec![(
                 "serial".to_owned(),
-                text.to_string(),
+                vec![text.to_string()],
             )])),
-            Choice::Map(map) => {
-                SerialNumber(map.into_iter().map(|(k, v)| (k, v.to_string())).collect())
-            }
+            Choice::Map(map) => SerialNumber(
+                map.into_iter()
+                    .filter_map(|(k, v)| {
+                        let values: Vec<_> = match v {
+                            OneOrMany::One(v) => vec![v.to_string()],
+                            OneOrMany::Many(v) => {
+                                v.iter().map(ToString::to_string).collect()
+                            }
+                        };
+                        (!values.is_empty()).then_some((k, values))
+                    })
+                    .collect(),
+            ),
         })
     }
 }
@@ -463,6 +607,32 @@ impl<'de> Deserialize<'de> for SerialNumber {
 mod tests {
     use super::*;
 
+    #[test]
+    fn default_parent_type() {
+        assert_eq!(EntryType::Article.default_parent_type(), Some(EntryType::Periodical));
+        assert_eq!(EntryType::Chapter.default_parent_type(), Some(EntryType::Book));
+        assert_eq!(EntryType::Book.default_parent_type(), None);
+        assert_eq!(EntryType::Web.default_parent_type(), None);
+    }
+
+    #[test]
+    fn maybe_typed_numeric() {
+        let typed = MaybeTyped::Typed(Numeric::new(3));
+        assert_eq!(typed.to_string(), "3");
+        assert_eq!(typed.as_i64(), Some(3));
+
+        let numeric: MaybeTyped<Numeric> = MaybeTyped::String(" 12 ".into());
+        assert_eq!(numeric.to_string(), " 12 ");
+        assert_eq!(numeric.as_i64(), Some(12));
+
+        let text: MaybeTyped<Numeric> = MaybeTyped::String("Second".into());
+        assert_eq!(text.to_string(), "Second");
+        assert_eq!(text.as_i64(), None);
+
+        let range = MaybeTyped::Typed(Numeric::from_str("1-2").unwrap());
+        assert_eq!(range.as_i64(), None);
+    }
+
     #[test]
     fn parse_num() {
         let val = Numeric::from_str("1").unwrap();
@@ -53,6 +53,15 @@ impl<'de> Deserialize<'de> for Numeric {
                 Ok(Numeric::new(v))
             }
 
+            /// Whole-number floats like `2.0` are accepted as integers.
+            fn visit_f64<E: Error>(self, v: f64) -> Result<Self::Value, E> {
+                if v.fract() != 0.0 || v < i32::MIN as f64 || v > i32::MAX as f64 {
+                    return Err(E::custom("expected a whole number"));
+                }
+
+                Ok(Numeric::new(v as i32))
+            }
+
             fn visit_str<E: Error>(self, v: &str) -> Result<Self::Value, E> {
                 Self::Value::from_str(v).map_err(|e| E::custom(e.to_string()))
             }
@@ -270,8 +279,13 @@ impl FromStr for Numeric {
 
     fn from_str(value: &str) -> Result<Self, Self::Err> {
         let mut s = Scanner::new(value);
-        let prefix =
+        let mut prefix =
             s.eat_while(|c: char| !c.is_numeric() && !c.is_whitespace() && c != '-');
+        // Keep the space between a label and the number, as in "vol. 3".
+        if !prefix.is_empty() {
+            s.eat_whitespace();
+            prefix = s.before();
+        }
 
         let value = number(&mut s).ok_or(NumericError::NoNumber)?;
         s.eat_whitespace();
@@ -447,7 +461,13 @@ impl std::fmt::Display for NumericDelimiter {
 }
 
 fn is_delimiter(c: char) -> bool {
-    c == ',' || c == '&' || c == '-' || c == '–'
+    c == ',' || c == '&' || is_dash(c)
+}
+
+/// Whether a character is a hyphen or one of the Unicode dashes that are
+/// often pasted into ranges, like the en dash in `10–20`.
+fn is_dash(c: char) -> bool {
+    matches!(c, '-' | '‐' | '‑' | '‒' | '–' | '—' | '―' | '−')
 }
 
 impl FromStr for NumericDelimiter {
@@ -455,7 +475,7 @@ impl FromStr for NumericDelimiter {
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let first_char = s.chars().next().ok_or(NumericError::MissingDelimiter)?;
-        if first_char != '-' && s.len() > first_char.len_utf8() {
+        if !is_dash(first_char) && s.len() > first_char.len_utf8() {
             return Err(NumericError::NotADelimiter);
         }
 
@@ -470,7 +490,7 @@ impl TryFrom<char> for NumericDelimiter {
         match c {
             ',' => Ok(NumericDelimiter::Comma),
             '&' => Ok(NumericDelimiter::Ampersand),
-            '-' | '–' => Ok(NumericDelimiter::Hyphen),
+            c if is_dash(c) => Ok(NumericDelimiter::Hyphen),
             _ => Err(NumericError::NotADelimiter),
         }
     }
@@ -493,4 +513,64 @@ mod test {
             ])
         );
     }
+
+    #[test]
+    fn test_unicode_dashes() {
+        for s in ["10-20", "10–20", "10—20", "10 — 20", "10‒20", "10−20", "10‐20"]
+        {
+            let n: Numeric = s.parse().unwrap();
+            assert_eq!(n.range(), Some(10..20), "{s}");
+            assert_eq!(n.to_string(), "10–20");
+        }
+
+        let n: Numeric = "1–3, 5——7".parse().unwrap();
+        assert_eq!(n.to_string(), "1–3, 5–7");
+
+        let entries = crate::io::from_yaml_str(
+            "dashes:\n    type: Book\n    page-range: 10—20\n    volume: 1–2\n",
+        )
+        .unwrap();
+        let entry = entries.get("dashes").unwrap();
+        assert_eq!(entry.page_range().unwrap().to_string(), "10–20");
+        assert_eq!(entry.volume().unwrap().to_string(), "1–2");
+        assert!(matches!(
+            entry.page_range(),
+            Some(MaybeTyped::Typed(n)) if n.range() == Some(10..20)
+        ));
+    }
+
+    #[test]
+    fn test_single_page() {
+        use crate::types::MaybeTyped;
+
+        let entries = crate::io::from_yaml_str(
+            "integer:\n    type: Book\n    page-range: 5\nstring:\n    type: Book\n    page-range: \"5\"\n",
+        )
+        .unwrap();
+        let integer = entries.get("integer").unwrap().page_range().unwrap();
+        let string = entries.get("string").unwrap().page_range().unwrap();
+        assert_eq!(integer, string);
+        assert_eq!(integer, &MaybeTyped::Typed(Numeric::new(5)));
+    }
+
+    #[test]
+    fn test_prefix_space() {
+        let n: Numeric = "vol. 3".parse().unwrap();
+        assert_eq!(n.prefix_str(), Some("vol. "));
+        assert_eq!(n.to_string(), "vol. 3");
+
+        let n: Numeric = "A16y".parse().unwrap();
+        assert_eq!(n.to_string(), "A16y");
+    }
+
+    #[test]
+    fn test_float() {
+        use crate::types::MaybeTyped;
+
+        let whole: MaybeTyped<Numeric> = serde_yaml::from_str("2.0").unwrap();
+        assert_eq!(whole, MaybeTyped::Typed(Numeric::new(2)));
+
+        let fraction: MaybeTyped<Numeric> = serde_yaml::from_str("2.5").unwrap();
+        assert_eq!(fraction, MaybeTyped::String("2.5".into()));
+    }
 }
@@ -1,4 +1,5 @@
 use std::borrow::Cow;
+use std::cell::OnceCell;
 use std::cmp::Ordering;
 use std::str::FromStr;
 
@@ -6,6 +7,8 @@ use citationberg::LongShortForm;
 use serde::ser::SerializeMap;
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
+use unic_langid::LanguageIdentifier;
+use unicode_normalization::{char::is_combining_mark, UnicodeNormalization};
 use unicode_segmentation::UnicodeSegmentation;
 
 use crate::lang::en::ARTICLES;
@@ -37,8 +40,11 @@ impl PersonsWithRoles {
 /// cited item.
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq, Hash)]
 #[non_exhaustive]
-#[serde(rename_all = "kebab-case")]
+#[serde(rename_all = "kebab-case", remote = "Self")]
 pub enum PersonRole {
+    /// Authored the cited item. Persons in the `author` field implicitly have
+    /// this role.
+    Author,
     /// Translated the work from a foreign language to the cited edition.
     Translator,
     /// Authored an afterword.
@@ -81,10 +87,37 @@ pub enum PersonRole {
     Narrator,
 
     /// Various other roles described by the contained string.
-    #[serde(skip)]
+    #[serde(untagged)]
     Unknown(String),
 }
 
+impl Serialize for PersonRole {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        PersonRole::serialize(self, serializer)
+    }
+}
+
+/// Roles are matched case-insensitively. Unknown roles keep their original
+/// spelling.
+impl<'de> Deserialize<'de> for PersonRole {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let role = String::deserialize(deserializer)?;
+        let lower = role.to_lowercase();
+        match PersonRole::deserialize(
+            serde::de::value::StrDeserializer::<D::Error>::new(&lower),
+        )? {
+            PersonRole::Unknown(_) => Ok(PersonRole::Unknown(role)),
+            known => Ok(known),
+        }
+    }
+}
+
 derive_or_from_str! {
     /// Holds the name of a person.
     #[derive(Clone, Debug, PartialEq, Eq, Hash)]
@@ -92,6 +125,7 @@ derive_or_from_str! {
         /// The family name.
         pub name: String,
         /// The given name / forename.
+        #[serde(alias = "given_name")]
         pub given_name: Option<String>,
         /// A prefix of the family name such as 'van' or 'de'.
         pub prefix: Option<String>,
@@ -99,17 +133,32 @@ derive_or_from_str! {
         pub suffix: Option<String>,
         /// Another name (often user name) the person might be known under.
         pub alias: Option<String>,
+        /// The ORCID identifier of the person, like `0000-0002-1825-0097`.
+        pub orcid: Option<String>,
     }
 }
 
+/// The parts of a person's name that [`Person::ident_key`] and
+/// [`Person::same_as`] compare.
+struct IdentParts {
+    surname: String,
+    initial: Option<char>,
+    orcid: Option<String>,
+}
+
 impl Serialize for Person {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: serde::Serializer,
     {
-        // Aliases are not represented in the string, prefixes can create
-        // ambiguity.
-        if self.alias.is_none() && self.prefix.is_none() {
+        // Aliases and ORCIDs are not represented in the string, prefixes can
+        // create ambiguity. Suffixes would be read back as given names because
+        // the string form expects them before the given name.
+        if self.alias.is_none()
+            && self.prefix.is_none()
+            && self.suffix.is_none()
+            && self.orcid.is_none()
+        {
             serializer.serialize_str(&self.name_first(false, false))
         } else {
             let entries = [
@@ -118,6 +167,7 @@ impl Serialize for Person {
                 ("prefix", self.prefix.as_ref()),
                 ("suffix", self.suffix.as_ref()),
                 ("alias", self.alias.as_ref()),
+                ("orcid", self.orcid.as_ref()),
             ];
 
             let map_len = entries.iter().filter(|(_, v)| v.is_some()).count();
@@ -227,7 +277,116 @@ impl Person {
             name = name.trim_start().to_string();
         }
 
-        Ok(Person { name, given_name, prefix, suffix, alias: None })
+        Ok(Person {
+            name,
+            given_name,
+            prefix,
+            suffix,
+            alias: None,
+            orcid: None,
+        })
+    }
+
+    /// Parse a name in one of the three forms of BibTeX's name grammar:
+    ///
+    /// - `First von Last`
+    /// - `von Last, First`
+    /// - `von Last, Jr, First`
+    ///
+    /// Commas inside braces do not separate parts. In the first form, the
+    /// given name ends and the `von` particle starts at the first lower case
+    /// word. The last word always belongs to the family name.
+    pub fn from_bibtex_name(name: &str) -> Result<Self, PersonError> {
+        let mut parts = vec![];
+        let mut depth = 0usize;
+        let mut start = 0;
+        for (i, c) in name.char_indices() {
+            match c {
+                '{' => depth += 1,
+                '}' => depth = depth.saturating_sub(1),
+                ',' if depth == 0 => {
+                    parts.push(&name[start..i]);
+                    start = i + 1;
+                }
+                _ => {}
+            }
+        }
+        parts.push(&name[start..]);
+
+        if parts.len() > 1 {
+            return Self::from_strings(parts);
+        }
+
+        let words: Vec<&str> = name.split_whitespace().collect();
+        let Some((_, rest)) = words.split_last() else {
+            return Err(PersonError::Empty);
+        };
+
+        // The particle spans from the first to the last lower case word
+        // before the final word.
+        let is_lower = |w: &&str| w.chars().next().is_some_and(char::is_lowercase);
+        let (given, prefix, family) = match rest.iter().position(is_lower) {
+            Some(first) => {
+                let last = rest.iter().rposition(is_lower).unwrap();
+                (&rest[..first], Some(rest[first..=last].join(" ")), &words[last + 1..])
+            }
+            None => (rest, None, &words[rest.len()..]),
+        };
+
+        Ok(Person {
+            name: family.join(" "),
+            given_name: Some(given.join(" ")).filter(|s| !s.is_empty()),
+            prefix,
+            suffix: None,
+            alias: None,
+            orcid: None,
+        })
+    }
+
+    /// Guess the parts of a name written in display order, like
+    /// `"John van Smith"`.
+    ///
+    /// Without a comma, it is ambiguous which words belong to the family name.
+    /// This heuristic assumes that the last word is the family name, that a
+    /// trailing generational suffix like `Jr.` or `III` is the suffix, and that
+    /// lower case particles like `van` or `de la` directly before the family
+    /// name are its prefix. Everything before that is the given name. Multi-word
+    /// family names like `"Gabriel García Márquez"` are therefore split
+    /// incorrectly; use [`Person::from_strings`] with a comma-separated name
+    /// when the order is known.
+    pub fn from_display_name(name: &str) -> Result<Self, PersonError> {
+        const SUFFIXES: &[&str] =
+            &["jr", "jr.", "sr", "sr.", "ii", "iii", "iv", "v", "vi"];
+
+        let mut words: Vec<&str> = name
+            .split_whitespace()
+            .map(|w| w.trim_end_matches(','))
+            .filter(|w| !w.is_empty())
+            .collect();
+
+        let mut suffix = None;
+        if words.len() > 1
+            && SUFFIXES.contains(&words.last().unwrap().to_lowercase().as_str())
+        {
+            suffix = words.pop().map(ToString::to_string);
+        }
+
+        let Some(family) = words.pop() else {
+            return Err(PersonError::Empty);
+        };
+
+        let is_particle = |w: &&&str| NAME_PARTICLES.binary_search(*w).is_ok();
+        let given_len = words.len() - words.iter().rev().take_while(is_particle).count();
+        let (given, prefix) = words.split_at(given_len);
+
+        Ok(Person {
+            name: family.to_string(),
+            given_name: Some(given.join(" ")).filter(|s| !s.is_empty()),
+            prefix: Some(prefix.join(" ")).filter(|s| !s.is_empty()),
+            suffix,
+            alias: None,
+            orcid: None,
+        })
     }
 
     /// Formats the given name into initials.
@@ -406,7 +565,7 @@ impl Person {
     }
 
     /// Get only the dropping and non-dropping particle of the family name.
-    pub fn name_particles(&self) -> Option<Cow<str>> {
+    pub fn name_particles(&self) -> Option<Cow<'_, str>> {
         match (&self.prefix, self.name_particle()) {
             (Some(dropping), Some(non_dropping)) => {
                 Some(Cow::Owned(format!("{} {}", dropping, non_dropping)))
@@ -417,6 +576,123 @@ impl Person {
         }
     }
 
+    /// Get a `"Surname, Given"` key to sort the person by in a bibliography
+    /// in the given language.
+    ///
+    /// Dutch ignores particles like `van` for sorting, so they move behind
+    /// the given name (`"Gogh, Vincent van"`). Other languages, like German,
+    /// sort by the particle (`"von Neumann, John"`).
+    pub fn sort_name(&self, lang: &LanguageIdentifier) -> String {
+        let particles = self.name_particles();
+        let mut res = String::new();
+        if lang.language.as_str() != "nl" {
+            if let Some(particles) = &particles {
+                res += particles;
+                res.push(' ');
+            }
+        }
+
+        res += self.name_without_particle();
+
+        let mut given = self.given_name.clone().unwrap_or_default();
+        if lang.language.as_str() == "nl" {
+            if let Some(particles) = &particles {
+                if !given.is_empty() {
+                    given.push(' ');
+                }
+                given += particles;
+            }
+        }
+
+        if !given.is_empty() {
+            res += ", ";
+            res += &given;
+        }
+
+        if let Some(suffix) = &self.suffix {
+            res += ", ";
+            res += suffix;
+        }
+
+        res
+    }
+
+    /// Get a `"surname|initial|orcid"` key to group the works of a person by,
+    /// e.g. in a `HashMap`.
+    ///
+    /// The family name (with its prefix) and the initial of the given name
+    /// are lowercased and stripped of diacritics, so that `"Müller, Jörg"`
+    /// and `"Muller, J."` share a key as long as their ORCIDs match.
+    pub fn ident_key(&self) -> String {
+        let IdentParts { surname, initial, orcid } = self.ident_parts();
+        format!(
+            "{}|{}|{}",
+            surname,
+            initial.map(String::from).unwrap_or_default(),
+            orcid.unwrap_or_default()
+        )
+    }
+
+    /// The folded family name, given name initial, and normalized ORCID that
+    /// identify a person.
+    fn ident_parts(&self) -> IdentParts {
+        let fold = |s: &str| -> String {
+            s.nfd()
+                .filter(|c| !is_combining_mark(*c))
+                .flat_map(char::to_lowercase)
+                .collect()
+        };
+
+        let mut surname = String::new();
+        if let Some(prefix) = &self.prefix {
+            surname += prefix;
+            surname.push(' ');
+        }
+        surname += &self.name;
+
+        let initial = self
+            .given_name
+            .as_deref()
+            .map(fold)
+            .and_then(|given| given.chars().find(|c| c.is_alphanumeric()));
+
+        let orcid = self.orcid.as_deref().map(|orcid| {
+            let orcid = orcid.trim();
+            let orcid = orcid
+                .strip_prefix("https://orcid.org/")
+                .or_else(|| orcid.strip_prefix("http://orcid.org/"))
+                .unwrap_or(orcid);
+            orcid.to_uppercase()
+        });
+
+        IdentParts { surname: fold(surname.trim()), initial, orcid }
+    }
+
+    /// Whether this is not a person but an "et al." standing in for the
+    /// remaining names of a list.
+    pub(crate) fn is_et_al(&self) -> bool {
+        let words = [self.prefix.as_deref(), Some(&self.name)]
+            .into_iter()
+            .flatten()
+            .flat_map(str::split_whitespace)
+            .map(|w| w.trim_end_matches('.').to_lowercase());
+        self.given_name.is_none() && self.suffix.is_none() && words.eq(["et", "al"])
+    }
+
+    /// Whether two names likely refer to the same person.
+    ///
+    /// If both persons have an ORCID, only the ORCIDs are compared. Otherwise,
+    /// the family name and given name initial are compared like in
+    /// [`Person::ident_key`].
+    pub fn same_as(&self, other: &Person) -> bool {
+        let (this, other) = (self.ident_parts(), other.ident_parts());
+        if this.orcid.is_some() && other.orcid.is_some() {
+            this.orcid == other.orcid
+        } else {
+            this.surname == other.surname && this.initial == other.initial
+        }
+    }
+
     /// Whether to treat this as an institutional name.
     pub fn is_institutional(&self) -> bool {
         self.given_name.is_none() && self.suffix.is_none() && self.prefix.is_none()
@@ -425,7 +701,7 @@ impl Person {
     /// Whether the name contains CJK characters.
     pub fn is_cjk(&self) -> bool {
         self.name.chars().any(is_cjk)
-            || self.given_name.as_ref().map_or(false, |gn| gn.chars().any(is_cjk))
+            || self.given_name.as_ref().is_some_and(|gn| gn.chars().any(is_cjk))
     }
 
     /// Get the name without the leading article.
@@ -522,9 +798,62 @@ impl FromStr for Person {
     }
 }
 
+/// Formats a [`Person`] and remembers the results.
+///
+/// Use this when the same person is rendered many times, for example in a
+/// large bibliography, to only compute each form of the name once.
+#[derive(Clone, Debug)]
+pub struct PersonFormatter<'a> {
+    person: &'a Person,
+    initials: OnceCell<String>,
+    name_first: [OnceCell<String>; 4],
+    given_first: [OnceCell<String>; 2],
+}
+
+impl<'a> PersonFormatter<'a> {
+    /// Create a new formatter for a person.
+    pub fn new(person: &'a Person) -> Self {
+        Self {
+            person,
+            initials: OnceCell::new(),
+            name_first: Default::default(),
+            given_first: Default::default(),
+        }
+    }
+
+    /// The formatted person.
+    pub fn person(&self) -> &'a Person {
+        self.person
+    }
+
+    /// The initials of the given name, delimited by periods. See
+    /// [`Person::initials`].
+    pub fn initials(&self) -> &str {
+        self.initials.get_or_init(|| {
+            let mut buf = String::new();
+            self.person.initials(&mut buf, Some("."), true).unwrap();
+            buf
+        })
+    }
+
+    /// The name with the family name first. See [`Person::name_first`].
+    pub fn name_first(&self, initials: bool, prefix_given_name: bool) -> &str {
+        let idx = usize::from(initials) << 1 | usize::from(prefix_given_name);
+        self.name_first[idx]
+            .get_or_init(|| self.person.name_first(initials, prefix_given_name))
+    }
+
+    /// The name with the given name first. See [`Person::given_first`].
+    pub fn given_first(&self, initials: bool) -> &str {
+        self.given_first[usize::from(initials)]
+            .get_or_init(|| self.person.given_first(initials))
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use super::Person;
+    use super::{Person, PersonFormatter, PersonRole};
+    use unic_langid::LanguageIdentifier;
 
     #[test]
     fn person_initials() {
@@ -539,6 +868,191 @@ mod tests {
         assert_eq!("H-J", s);
     }
 
+    #[test]
+    fn bibtex_names() {
+        let p = Person::from_bibtex_name("Ludwig van Beethoven").unwrap();
+        assert_eq!(p.given_name.as_deref(), Some("Ludwig"));
+        assert_eq!(p.prefix.as_deref(), Some("van"));
+        assert_eq!(p.name, "Beethoven");
+
+        let p = Person::from_bibtex_name("Jean de la Fontaine Martin").unwrap();
+        assert_eq!(p.given_name.as_deref(), Some("Jean"));
+        assert_eq!(p.prefix.as_deref(), Some("de la"));
+        assert_eq!(p.name, "Fontaine Martin");
+
+        let p = Person::from_bibtex_name("Donald E. Knuth").unwrap();
+        assert_eq!(p.given_name.as_deref(), Some("Donald E."));
+        assert_eq!(p.prefix, None);
+        assert_eq!(p.name, "Knuth");
+
+        let p = Person::from_bibtex_name("Aristotle").unwrap();
+        assert_eq!(p.given_name, None);
+        assert_eq!(p.name, "Aristotle");
+
+        let p = Person::from_bibtex_name("van Beethoven, Ludwig").unwrap();
+        assert_eq!(p.given_name.as_deref(), Some("Ludwig"));
+        assert_eq!(p.prefix.as_deref(), Some("van"));
+        assert_eq!(p.name, "Beethoven");
+
+        let p = Person::from_bibtex_name("von Neumann, Jr, John").unwrap();
+        assert_eq!(p.given_name.as_deref(), Some("John"));
+        assert_eq!(p.prefix.as_deref(), Some("von"));
+        assert_eq!(p.name, "Neumann");
+        assert_eq!(p.suffix.as_deref(), Some("Jr"));
+
+        let p = Person::from_bibtex_name("{Barnes, Noble}, Inc").unwrap();
+        assert_eq!(p.name, "{Barnes, Noble}");
+        assert_eq!(p.given_name.as_deref(), Some("Inc"));
+
+        assert!(Person::from_bibtex_name("  ").is_err());
+        assert!(Person::from_bibtex_name("a, b, c, d").is_err());
+    }
+
+    #[test]
+    fn person_formatter() {
+        let people = [
+            Person::from_strings(vec!["van Beethoven", "Ludwig"]).unwrap(),
+            Person::from_strings(vec!["Fontaine", "Jr.", "Jean Baptiste"]).unwrap(),
+            Person::from_strings(vec!["Aristotle"]).unwrap(),
+        ];
+
+        for person in &people {
+            let formatter = PersonFormatter::new(person);
+            for _ in 0..100 {
+                for initials in [false, true] {
+                    assert_eq!(
+                        formatter.given_first(initials),
+                        person.given_first(initials)
+                    );
+                    for prefix in [false, true] {
+                        assert_eq!(
+                            formatter.name_first(initials, prefix),
+                            person.name_first(initials, prefix)
+                        );
+                    }
+                }
+
+                let mut initials = String::new();
+                person.initials(&mut initials, Some("."), true).unwrap();
+                assert_eq!(formatter.initials(), initials);
+            }
+        }
+
+        assert_eq!(PersonFormatter::new(&people[1]).initials(), "J. B.");
+    }
+
+    #[test]
+    fn ident_key() {
+        let person = |yaml: &str| serde_yaml::from_str::<Person>(yaml).unwrap();
+
+        let a = person("{name: Müller, given-name: Jörg, orcid: 0000-0002-1825-009x}");
+        let b = person("{name: Muller, given-name: J., orcid: https://orcid.org/0000-0002-1825-009X}");
+        let c = person("{name: Muller, given-name: J., orcid: 0000-0001-5109-3700}");
+        let d = person("Muller, J.");
+
+        assert_eq!(a.ident_key(), "muller|j|0000-0002-1825-009X");
+        assert_eq!(a.ident_key(), b.ident_key());
+        assert_ne!(b.ident_key(), c.ident_key());
+        assert_eq!(d.ident_key(), "muller|j|");
+
+        let mut works = std::collections::HashMap::new();
+        for person in [&a, &b, &c] {
+            *works.entry(person.ident_key()).or_insert(0) += 1;
+        }
+        assert_eq!(works[&a.ident_key()], 2);
+
+        let prefixed = person("{name: Gogh, given-name: Vincent, prefix: van}");
+        assert_eq!(prefixed.ident_key(), "van gogh|v|");
+        assert!(serde_yaml::to_string(&a).unwrap().contains("orcid"));
+    }
+
+    #[test]
+    fn same_as() {
+        let person = |yaml: &str| serde_yaml::from_str::<Person>(yaml).unwrap();
+
+        let a = person("{name: Müller, given-name: Jörg, orcid: 0000-0002-1825-009x}");
+        let b = person("{name: Schmidt, given-name: J., orcid: 0000-0002-1825-009X}");
+        let c = person("{name: Muller, given-name: J., orcid: 0000-0001-5109-3700}");
+        let d = person("Muller, J.");
+
+        assert!(a.same_as(&b));
+        assert!(!a.same_as(&c));
+        assert!(a.same_as(&d) && c.same_as(&d));
+        assert!(!d.same_as(&person("Muller, K.")));
+        assert!(
+            !person("{name: A, orcid: 1|X}").same_as(&person("{name: A, orcid: 2|X}"))
+        );
+    }
+
+    #[test]
+    fn sort_names() {
+        let nl: LanguageIdentifier = "nl".parse().unwrap();
+        let de: LanguageIdentifier = "de".parse().unwrap();
+
+        let gogh = Person::from_strings(vec!["van Gogh", "Vincent"]).unwrap();
+        assert_eq!(gogh.sort_name(&nl), "Gogh, Vincent van");
+        assert_eq!(gogh.sort_name(&de), "van Gogh, Vincent");
+
+        let neumann = Person::from_strings(vec!["von Neumann", "Jr.", "John"]).unwrap();
+        assert_eq!(neumann.sort_name(&de), "von Neumann, John, Jr.");
+        assert_eq!(neumann.sort_name(&nl), "Neumann, John von, Jr.");
+
+        let mut dutch = [
+            Person::from_strings(vec!["de Vries", "Hugo"]).unwrap(),
+            Person::from_strings(vec!["Hals", "Frans"]).unwrap(),
+            gogh.clone(),
+        ];
+        dutch.sort_by_key(|p| p.sort_name(&nl));
+        assert_eq!(
+            dutch.iter().map(|p| p.name.as_str()).collect::<Vec<_>>(),
+            ["Gogh", "Hals", "Vries"]
+        );
+
+        let mut german = [
+            neumann.clone(),
+            Person::from_strings(vec!["Müller", "Anna"]).unwrap(),
+            Person::from_strings(vec!["Weber", "Max"]).unwrap(),
+        ];
+        german.sort_by_key(|p| p.sort_name(&de));
+        assert_eq!(
+            german.iter().map(|p| p.name.as_str()).collect::<Vec<_>>(),
+            ["Müller", "Weber", "Neumann"]
+        );
+        german.sort_by_key(|p| p.sort_name(&nl));
+        assert_eq!(
+            german.iter().map(|p| p.name.as_str()).collect::<Vec<_>>(),
+            ["Müller", "Neumann", "Weber"]
+        );
+
+        let aristotle = Person::from_strings(vec!["Aristotle"]).unwrap();
+        assert_eq!(aristotle.sort_name(&nl), "Aristotle");
+    }
+
+    #[test]
+    fn display_names() {
+        let p = Person::from_display_name("John Smith").unwrap();
+        assert_eq!(p.given_name.as_deref(), Some("John"));
+        assert_eq!(p.prefix, None);
+        assert_eq!(p.name, "Smith");
+
+        let p = Person::from_display_name("John van Smith").unwrap();
+        assert_eq!(p.given_name.as_deref(), Some("John"));
+        assert_eq!(p.prefix.as_deref(), Some("van"));
+        assert_eq!(p.name, "Smith");
+
+        let p = Person::from_display_name("Jean Baptiste de la Fontaine, Jr.").unwrap();
+        assert_eq!(p.given_name.as_deref(), Some("Jean Baptiste"));
+        assert_eq!(p.prefix.as_deref(), Some("de la"));
+        assert_eq!(p.name, "Fontaine");
+        assert_eq!(p.suffix.as_deref(), Some("Jr."));
+
+        let p = Person::from_display_name("Aristotle").unwrap();
+        assert_eq!(p.given_name, None);
+        assert_eq!(p.name, "Aristotle");
+
+        assert!(Person::from_display_name("  ").is_err());
+    }
+
     #[test]
     fn person_non_dropping_initials() {
         let p = Person::from_strings(vec!["Von Der Leyen", "Ursula"]).unwrap();
@@ -552,4 +1066,39 @@ mod tests {
         p.first_name_with_delimiter(&mut s, Some(".")).unwrap();
         assert_eq!("James T.", s);
     }
+
+    #[test]
+    fn serialize_forms() {
+        let plain = Person::from_strings(vec!["King", "Martin Luther"]).unwrap();
+        let yaml = serde_yaml::to_string(&plain).unwrap();
+        assert_eq!(yaml.trim(), "King, Martin Luther");
+        assert_eq!(serde_yaml::from_str::<Person>(&yaml).unwrap(), plain);
+
+        let suffixed =
+            Person::from_strings(vec!["King", "Jr.", "Martin Luther"]).unwrap();
+        assert_eq!(suffixed.suffix.as_deref(), Some("Jr."));
+        let yaml = serde_yaml::to_string(&suffixed).unwrap();
+        assert!(yaml.contains("suffix: Jr."));
+        assert!(yaml.contains("given-name: Martin Luther"));
+        assert_eq!(serde_yaml::from_str::<Person>(&yaml).unwrap(), suffixed);
+    }
+
+    #[test]
+    fn role_case() {
+        let role = |s: &str| serde_yaml::from_str::<PersonRole>(s).unwrap();
+        assert_eq!(role("translator"), PersonRole::Translator);
+        assert_eq!(role("Translator"), PersonRole::Translator);
+        assert_eq!(role("Cast-Member"), PersonRole::CastMember);
+        assert_eq!(role("Dramaturg"), PersonRole::Unknown("Dramaturg".into()));
+        assert_eq!(
+            serde_yaml::to_string(&PersonRole::CastMember).unwrap().trim(),
+            "cast-member"
+        );
+        assert_eq!(
+            serde_yaml::to_string(&PersonRole::Unknown("Dramaturg".into()))
+                .unwrap()
+                .trim(),
+            "Dramaturg"
+        );
+    }
 }
@@ -6,6 +6,7 @@ use std::str::FromStr;
 use citationberg::LongShortForm;
 use serde::{de::Visitor, ser::SerializeMap, Deserialize, Serialize};
 use thiserror::Error;
+use unic_langid::LanguageIdentifier;
 use unscanny::Scanner;
 
 use crate::lang::{Case, CaseFolder, SentenceCase, TitleCase};
@@ -19,6 +20,9 @@ pub struct FormatString {
     pub value: ChunkedString,
     /// The short version of the string.
     pub short: Option<Box<ChunkedString>>,
+    /// The language of the string if it differs from the language of the
+    /// entry. Case transformations follow this language.
+    pub language: Option<LanguageIdentifier>,
 }
 
 impl<'de> Deserialize<'de> for FormatString {
@@ -52,15 +56,19 @@ impl<'de> Deserialize<'de> for FormatString {
                 #[derive(Deserialize)]
                 #[serde(untagged)]
                 enum Inner {
-                    Full { value: ChunkedString, short: Option<ChunkedString> },
+                    Full {
+                        value: ChunkedString,
+                        short: Option<ChunkedString>,
+                        language: Option<LanguageIdentifier>,
+                    },
                     Val(ChunkedString),
                 }
 
                 Deserialize::deserialize(de::value::MapAccessDeserializer::new(map)).map(
                     |inner: Inner| match inner {
-                        Inner::Val(value) => Self::Value { value, short: None },
-                        Inner::Full { value, short } => {
-                            Self::Value { value, short: short.map(Box::new) }
+                        Inner::Val(value) => Self::Value { value, ..Default::default() },
+                        Inner::Full { value, short, language } => {
+                            Self::Value { value, short: short.map(Box::new), language }
                         }
                     },
                 )
@@ -76,10 +84,18 @@ impl Serialize for FormatString {
     where
         S: serde::Serializer,
     {
-        if let Some(short) = &self.short {
-            let mut map = serializer.serialize_map(Some(2))?;
+        if self.short.is_some() || self.language.is_some() {
+            let len = 1
+                + usize::from(self.short.is_some())
+                + usize::from(self.language.is_some());
+            let mut map = serializer.serialize_map(Some(len))?;
             map.serialize_entry("value", &self.value)?;
-            map.serialize_entry("short", short)?;
+            if let Some(short) = &self.short {
+                map.serialize_entry("short", short)?;
+            }
+            if let Some(language) = &self.language {
+                map.serialize_entry("language", language)?;
+            }
             map.end()
         } else {
             self.value.serialize(serializer)
@@ -98,6 +114,7 @@ impl FormatString {
         Self {
             value: StringChunk::normal(value).into(),
             short: None,
+            language: None,
         }
     }
 
@@ -106,6 +123,7 @@ impl FormatString {
         Self {
             value: StringChunk::normal(value).into(),
             short: Some(Box::new(StringChunk::normal(short).into())),
+            language: None,
         }
     }
 
@@ -139,6 +157,71 @@ impl FormatString {
             LongShortForm::Short => self.short.as_deref().unwrap_or(&self.value),
         }
     }
+
+    /// Whether the canonical version of the string is empty or only consists
+    /// of whitespace.
+    pub fn is_empty(&self) -> bool {
+        self.value.to_str().trim().is_empty()
+    }
+
+    /// Remove leading and trailing whitespace from the long and short version.
+    /// A short version that becomes empty is removed.
+    pub fn trim(&mut self) {
+        self.value.trim();
+        if let Some(short) = &mut self.short {
+            short.trim();
+            if short.is_empty() {
+                self.short = None;
+            }
+        }
+    }
+
+    /// Joins two strings with a separator, e.g. a title and its subtitle.
+    ///
+    /// The short versions are joined pairwise, falling back to the long
+    /// version of a string without a short version. The result only has a
+    /// short version if one of the inputs has one. Chunk kinds are preserved.
+    pub fn concat(&self, sep: &str, other: &FormatString) -> FormatString {
+        let join = |a: &ChunkedString, b: &ChunkedString| {
+            let mut joined = a.clone();
+            joined.push_str(sep, ChunkKind::Normal);
+            joined.extend(b.clone());
+            joined
+        };
+
+        let short = (self.short.is_some() || other.short.is_some()).then(|| {
+            Box::new(join(
+                self.select(LongShortForm::Short),
+                other.select(LongShortForm::Short),
+            ))
+        });
+
+        FormatString {
+            value: join(&self.value, &other.value),
+            short,
+            language: self.language.clone(),
+        }
+    }
+
+    /// Apply a transformation to the long and the short version.
+    ///
+    /// The function is called for every chunk on its own so that the chunk
+    /// kinds are preserved.
+    pub fn map(&self, f: impl Fn(&str) -> String) -> FormatString {
+        let map = |s: &ChunkedString| {
+            ChunkedString(
+                s.0.iter()
+                    .map(|chunk| StringChunk::new(f(&chunk.value), chunk.kind))
+                    .collect(),
+            )
+        };
+
+        FormatString {
+            value: map(&self.value),
+            short: self.short.as_deref().map(|short| Box::new(map(short))),
+            language: self.language.clone(),
+        }
+    }
 }
 
 impl fmt::Display for FormatString {
@@ -155,7 +238,7 @@ impl From<String> for FormatString {
 
 impl From<StringChunk> for FormatString {
     fn from(chunk: StringChunk) -> Self {
-        Self { value: chunk.into(), short: None }
+        Self { value: chunk.into(), short: None, language: None }
     }
 }
 
@@ -163,7 +246,11 @@ impl FromStr for FormatString {
     type Err = ChunkedStrParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        Ok(Self { value: ChunkedString::from_str(s)?, short: None })
+        Ok(Self {
+            value: ChunkedString::from_str(s)?,
+            short: None,
+            language: None,
+        })
     }
 }
 
@@ -319,6 +406,34 @@ impl ChunkedString {
         self.0.iter().all(|c| c.is_empty())
     }
 
+    /// Remove leading and trailing whitespace of normal chunks. Verbatim and
+    /// math chunks are kept as-is.
+    pub fn trim(&mut self) {
+        while let Some(first) = self.0.first_mut() {
+            if first.kind != ChunkKind::Normal {
+                break;
+            }
+
+            first.value = first.value.trim_start().to_string();
+            if !first.value.is_empty() {
+                break;
+            }
+            self.0.remove(0);
+        }
+
+        while let Some(last) = self.0.last_mut() {
+            if last.kind != ChunkKind::Normal {
+                break;
+            }
+
+            last.value.truncate(last.value.trim_end().len());
+            if !last.value.is_empty() {
+                break;
+            }
+            self.0.pop();
+        }
+    }
+
     /// Extend with another `ChunkedString`.
     pub fn extend(&mut self, other: Self) {
         self.0.extend(other.0);
@@ -627,6 +742,68 @@ impl From<FoldableKind> for ChunkKind {
 mod tests {
     use super::*;
 
+    #[test]
+    fn concat() {
+        let title = FormatString::with_short("Crazy Rich Asians", "CRA");
+        let subtitle: FormatString = "A {Novel}".parse().unwrap();
+        let joined = title.concat(": ", &subtitle);
+
+        assert_eq!(joined.value.to_string(), "Crazy Rich Asians: A Novel");
+        assert_eq!(joined.value.0.last().unwrap().kind, ChunkKind::Verbatim);
+        assert_eq!(joined.short.unwrap().to_string(), "CRA: A Novel");
+
+        let plain = FormatString::with_value("Title").concat(": ", &subtitle);
+        assert_eq!(plain.value.to_string(), "Title: A Novel");
+        assert_eq!(plain.short, None);
+    }
+
+    #[test]
+    fn language() {
+        let s: FormatString =
+            serde_yaml::from_str("{value: Der Process, language: de}").unwrap();
+        assert_eq!(s.value.to_string(), "Der Process");
+        assert_eq!(s.language.as_ref().unwrap().language.as_str(), "de");
+        assert_eq!(
+            serde_yaml::from_str::<FormatString>(&serde_yaml::to_string(&s).unwrap())
+                .unwrap(),
+            s
+        );
+
+        let plain: FormatString = serde_yaml::from_str("{value: The Trial}").unwrap();
+        assert_eq!(plain.language, None);
+        assert_eq!(serde_yaml::to_string(&plain).unwrap(), "The Trial\n");
+    }
+
+    #[test]
+    fn map() {
+        let mut title: FormatString = "Colour in {Colour Science}".parse().unwrap();
+        title.short = Some(Box::new("Colour".parse().unwrap()));
+        let mapped = title.map(|s| s.replace("Colour", "Color"));
+
+        assert_eq!(mapped.value.to_string(), "Color in Color Science");
+        assert_eq!(mapped.value.0[1].kind, ChunkKind::Verbatim);
+        assert_eq!(mapped.short.unwrap().to_string(), "Color");
+        assert_eq!(FormatString::with_value("Colour").map(str::to_uppercase).short, None);
+    }
+
+    #[test]
+    fn trim() {
+        let mut s = FormatString::from_str("  Hello {World }  ").unwrap();
+        s.trim();
+        assert_eq!(s.value.0.len(), 2);
+        assert_eq!(s.value.0[0].value, "Hello ");
+        assert_eq!(s.value.0[1].value, "World ");
+
+        let mut s = FormatString::with_short("  Title ", "  ");
+        s.trim();
+        assert_eq!(s, FormatString::with_value("Title"));
+
+        let mut s = FormatString::with_value(" \t ");
+        s.trim();
+        assert!(s.value.is_empty());
+        assert!(s.value.0.is_empty());
+    }
+
     #[test]
     fn escaped_brace() {
         let str = "Hello {World\\{}";
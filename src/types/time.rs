@@ -4,8 +4,11 @@ use std::{
     str::FromStr,
 };
 
+use citationberg::taxonomy::OtherTerm;
+use citationberg::{Locale, TermForm};
 use serde::{de, Deserialize, Serialize};
 use thiserror::Error;
+use unic_langid::LanguageIdentifier;
 use unscanny::Scanner;
 
 use super::{derive_or_from_str, deserialize_from_str, serialize_display};
@@ -59,14 +62,43 @@ impl<'de> Deserialize<'de> for Date {
                     pub approximate: bool,
                 }
 
-                Deserialize::deserialize(de::value::MapAccessDeserializer::new(map)).map(
-                    |inner: Inner| Date {
+                let inner: Inner =
+                    Deserialize::deserialize(de::value::MapAccessDeserializer::new(map))?;
+
+                // The map form uses zero-based months and days.
+                match (inner.month, inner.day) {
+                    (Some(month), _) if month > 11 => Err(DateError::MonthOutOfBounds),
+                    (None, Some(_)) => Err(DateError::DayWithoutMonth),
+                    (Some(month), Some(day))
+                        if day >= days_in_month(month, inner.year) =>
+                    {
+                        Err(DateError::DayOutOfBounds(day.saturating_add(1)))
+                    }
+                    _ => Ok(Date {
                         year: inner.year,
                         month: inner.month,
                         day: inner.day,
                         approximate: inner.approximate,
-                    },
-                )
+                    }),
+                }
+                .map_err(|e| de::Error::custom(e.to_string()))
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: serde::de::SeqAccess<'de>,
+            {
+                let year: i32 = seq
+                    .next_element()?
+                    .ok_or_else(|| de::Error::invalid_length(0, &"1 to 3 date parts"))?;
+                let month: Option<u8> = seq.next_element()?;
+                let day: Option<u8> =
+                    if month.is_some() { seq.next_element()? } else { None };
+                if seq.next_element::<de::IgnoredAny>()?.is_some() {
+                    return Err(de::Error::invalid_length(4, &"1 to 3 date parts"));
+                }
+
+                Date::new(year, month, day).map_err(|e| de::Error::custom(e.to_string()))
             }
 
             fn visit_u64<E>(self, value: u64) -> Result<Self::Value, E>
@@ -166,6 +198,15 @@ pub enum DateError {
     /// The day is out of bounds.
     #[error("month has no day {0}")]
     DayOutOfBounds(u8),
+    /// A day was given without a month.
+    #[error("day given without month")]
+    DayWithoutMonth,
+    /// The ISO week is out of bounds.
+    #[error("year has no week {0}")]
+    WeekOutOfBounds(u8),
+    /// The day of the year or of the week is out of bounds.
+    #[error("year has no day {0}")]
+    DayOfYearOutOfBounds(u16),
 }
 
 impl FromStr for Date {
@@ -173,12 +214,103 @@ impl FromStr for Date {
 
     /// Parse a date from a string.
     fn from_str(source: &str) -> Result<Self, Self::Err> {
+        Self::parse(source, None)
+    }
+}
+
+impl Date {
+    /// Parse a date from a string, expanding two-digit years like `99` or
+    /// `'99`.
+    ///
+    /// Two-digit years up to and including `pivot` are placed in the 2000s,
+    /// all others in the 1900s. Four-digit years are always taken literally.
+    /// [`Date::default_year_pivot`] provides a sensible pivot.
+    pub fn from_str_with_pivot(source: &str, pivot: u8) -> Result<Self, DateError> {
+        Self::parse(source, Some(pivot))
+    }
+
+    /// Parse a date, also accepting numeric dates like `01/05/2020` or
+    /// `1.5.2020` that are common in some locales.
+    ///
+    /// The language decides whether the day or the month comes first: U.S.
+    /// English (and English without a region) puts the month first, all other
+    /// languages put the day first. Dates starting with a four-digit year are
+    /// always read as year, month, and day.
+    pub fn parse_flexible(
+        source: &str,
+        lang: &LanguageIdentifier,
+    ) -> Result<Self, DateError> {
+        match Self::from_str(source) {
+            Err(DateError::UnknownFormat) => {}
+            res => return res,
+        }
+
+        let parts: Vec<&str> =
+            source.trim().split(['/', '.', '-']).map(str::trim).collect();
+        let [first, second, third] = parts[..] else {
+            return Err(DateError::UnknownFormat);
+        };
+
+        let number = |s: &str, digits: std::ops::RangeInclusive<usize>| {
+            if !digits.contains(&s.len()) || !s.bytes().all(|b| b.is_ascii_digit()) {
+                return Err(DateError::UnknownFormat);
+            }
+            s.parse::<i32>().map_err(|_| DateError::UnknownFormat)
+        };
+
+        let (year, month, day) = if first.len() == 4 {
+            (number(first, 4..=4)?, number(second, 1..=2)?, number(third, 1..=2)?)
+        } else {
+            let year = number(third, 4..=4)?;
+            let (first, second) = (number(first, 1..=2)?, number(second, 1..=2)?);
+            let month_first = match lang.region {
+                Some(region) => region.as_str() == "US",
+                None => lang.language.as_str() == "en",
+            };
+
+            if month_first {
+                (year, first, second)
+            } else {
+                (year, second, first)
+            }
+        };
+
+        Self::new(year, Some(month as u8), Some(day as u8))
+    }
+
+    /// The default pivot for two-digit years: Years up to the next calendar
+    /// year are placed in the 2000s.
+    pub fn default_year_pivot() -> u8 {
+        ((current_year() + 1).rem_euclid(100)) as u8
+    }
+
+    fn parse(source: &str, pivot: Option<u8>) -> Result<Self, DateError> {
+        // Dates copied from running text often end with a period.
+        let source = source.trim();
+        let source = source.strip_suffix('.').unwrap_or(source);
         let mut s = Scanner::new(source);
         s.eat_whitespace();
         let approx = s.eat_if('~');
 
         let idx = s.cursor();
-        match parse_full_date(&mut s) {
+        match parse_full_date(&mut s, pivot) {
+            Ok((year, month, day)) => {
+                return Ok(Self {
+                    year,
+                    month: Some(month),
+                    day: Some(day),
+                    approximate: approx,
+                });
+            }
+            Err(DateError::UnknownFormat) => {
+                s.jump(idx);
+            }
+            Err(e) => {
+                return Err(e);
+            }
+        }
+
+        match parse_week_or_ordinal_date(&mut s) {
             Ok((year, month, day)) => {
                 return Ok(Self {
                     year,
@@ -195,7 +327,7 @@ impl FromStr for Date {
             }
         }
 
-        match parse_month_with_year(&mut s) {
+        match parse_month_with_year(&mut s, pivot) {
             Ok((year, month)) => {
                 return Ok(Self {
                     year,
@@ -212,7 +344,7 @@ impl FromStr for Date {
             }
         }
 
-        let year = parse_year(&mut s)?;
+        let year = parse_any_year(&mut s, pivot)?;
         s.eat_whitespace();
         if !s.done() {
             return Err(DateError::UnknownFormat);
@@ -228,6 +360,47 @@ impl Date {
         Self { year, month: None, day: None, approximate: false }
     }
 
+    /// Create a date from a year, a month (1-12), and a day of the month,
+    /// checking that the date exists.
+    ///
+    /// Note that the `month` and `day` fields of the resulting date are
+    /// zero-based.
+    pub fn new(year: i32, month: Option<u8>, day: Option<u8>) -> Result<Self, DateError> {
+        let month = match month {
+            Some(month) if !(1..=12).contains(&month) => {
+                return Err(DateError::MonthOutOfBounds);
+            }
+            Some(month) => Some(month - 1),
+            None if day.is_some() => return Err(DateError::DayWithoutMonth),
+            None => None,
+        };
+
+        if let (Some(month), Some(day)) = (month, day) {
+            if day == 0 || day > days_in_month(month, year) {
+                return Err(DateError::DayOutOfBounds(day));
+            }
+        }
+
+        Ok(Self {
+            year,
+            month,
+            day: day.map(|d| d - 1),
+            approximate: false,
+        })
+    }
+
+    /// The month as it is written in a calendar (1-12), unlike the
+    /// zero-based `month` field.
+    pub fn calendar_month(&self) -> Option<i32> {
+        self.month.map(|m| i32::from(m) + 1)
+    }
+
+    /// The day of the month as it is written in a calendar (1-31), unlike the
+    /// zero-based `day` field.
+    pub fn calendar_day(&self) -> Option<i32> {
+        self.day.map(|d| i32::from(d) + 1)
+    }
+
     /// Returns the year as a human-readable gregorian year.
     ///
     /// Non-positive values will be marked with a "BCE" postfix.
@@ -235,6 +408,53 @@ impl Date {
         self.display_year_opt(true, false, false, false)
     }
 
+    /// The number of whole years between `other` and this date, negative if
+    /// this date is earlier.
+    ///
+    /// Months and days are only taken into account if both dates specify
+    /// them. Returns `None` if the difference overflows.
+    pub fn years_since(&self, other: &Date) -> Option<i32> {
+        let years = self.year.checked_sub(other.year)?;
+        let (this, that) = match (self.month, other.month) {
+            (Some(m1), Some(m2)) => match (self.day, other.day) {
+                (Some(d1), Some(d2)) => ((m1, d1), (m2, d2)),
+                _ => ((m1, 0), (m2, 0)),
+            },
+            _ => return Some(years),
+        };
+
+        Some(if years > 0 && this < that {
+            years - 1
+        } else if years < 0 && this > that {
+            years + 1
+        } else {
+            years
+        })
+    }
+
+    /// Returns the year as a human-readable gregorian year, prefixed with the
+    /// "circa" term of the given CSL locale if the date is approximate.
+    ///
+    /// If the locale does not define the term, only the year is returned.
+    pub fn display_with_certainty(&self, locale: &Locale) -> String {
+        let year = self.display_year();
+        if !self.approximate {
+            return year;
+        }
+
+        let mut form = Some(TermForm::Short);
+        while let Some(current) = form {
+            if let Some(circa) =
+                locale.term(OtherTerm::Circa.into(), current).and_then(|t| t.single())
+            {
+                return format!("{} {}", circa, year);
+            }
+            form = current.fallback();
+        }
+
+        year
+    }
+
     /// Returns the year as a human-readable gregorian year with controllable
     /// pre- and postfixes denominating the year's positivity.
     ///
@@ -243,7 +463,7 @@ impl Date {
     /// - `periods`   Determines whether to use punctuation in the abbreviations
     /// - `designate_positive`    Show a denomination for positive years
     /// - `ad_prefix` Use the "AD" designation for positive years in a prefix
-    ///               position. Will be ignored if `designate_positive` is negative.
+    ///   position. Will be ignored if `designate_positive` is negative.
     pub fn display_year_opt(
         &self,
         secular: bool,
@@ -304,7 +524,8 @@ impl Serialize for Date {
     where
         S: serde::Serializer,
     {
-        if self.month.is_none() {
+        // An integer cannot mark the year as approximate.
+        if self.month.is_none() && !self.approximate {
             serializer.serialize_i32(self.year)
         } else {
             serializer.serialize_str(&self.to_string())
@@ -434,6 +655,78 @@ impl Duration {
         Ok(Duration { days, hours, minutes, seconds, milliseconds })
     }
 
+    /// Parse an SMPTE timecode of the form `HH:MM:SS:FF` at the given frame
+    /// rate. The frames are converted to milliseconds, rounded to the nearest
+    /// millisecond.
+    pub fn from_timecode(s: &str, fps: u32) -> Result<Self, DurationError> {
+        if fps == 0 {
+            return Err(DurationError::Malformed);
+        }
+
+        let mut s = Scanner::new(s);
+        let mut parts = [0u32; 4];
+        for (i, part) in parts.iter_mut().enumerate() {
+            if i > 0 {
+                s.eat_whitespace();
+                if !s.eat_if(':') {
+                    return Err(DurationError::Malformed);
+                }
+            }
+
+            let digits = if i == 0 { 2..=usize::MAX } else { 2..=2 };
+            *part = parse_unsigned_int(&mut s, digits).ok_or(DurationError::Malformed)?;
+        }
+
+        s.eat_whitespace();
+        if !s.done() {
+            return Err(DurationError::Malformed);
+        }
+
+        let [hours, minutes, seconds, frames] = parts;
+        if minutes >= 60 || seconds >= 60 {
+            return Err(DurationError::TooLarge);
+        }
+        if frames >= fps {
+            return Err(DurationError::FrameOutOfBounds(frames, fps));
+        }
+
+        let milliseconds = ((frames as u64 * 1000 + fps as u64 / 2) / fps as u64) as u16;
+        Ok(Duration {
+            days: hours / 24,
+            hours: hours % 24,
+            minutes,
+            seconds: seconds as u8,
+            milliseconds,
+        })
+    }
+
+    /// Create a duration from a number of seconds, rounded to the nearest
+    /// millisecond. Negative and non-finite values yield an empty duration.
+    pub fn from_seconds(seconds: f64) -> Self {
+        if !seconds.is_finite() || seconds <= 0.0 {
+            return Self::default();
+        }
+
+        Self::from_milliseconds((seconds * 1000.0).round() as u64)
+    }
+
+    /// Create a duration from a number of milliseconds. The days saturate
+    /// at their maximum.
+    fn from_milliseconds(ms: u64) -> Self {
+        Self {
+            days: (ms / (24 * 60 * 60 * 1000)).min(u32::MAX as u64) as u32,
+            hours: (ms / (60 * 60 * 1000) % 24) as u32,
+            minutes: (ms / (60 * 1000) % 60) as u32,
+            seconds: (ms / 1000 % 60) as u8,
+            milliseconds: (ms % 1000) as u16,
+        }
+    }
+
+    /// Get the duration in seconds, including the milliseconds as a fraction.
+    pub fn as_seconds(&self) -> f64 {
+        self.milliseconds() as f64 / 1000.0
+    }
+
     /// Get the duration in milliseconds.
     fn milliseconds(self) -> u64 {
         self.milliseconds as u64
@@ -456,6 +749,20 @@ impl Ord for Duration {
     }
 }
 
+impl std::ops::Add for Duration {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self::from_milliseconds(self.milliseconds().saturating_add(other.milliseconds()))
+    }
+}
+
+impl std::iter::Sum for Duration {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::default(), |acc, d| acc + d)
+    }
+}
+
 /// Errors that can occur when parsing a string to a duration
 #[derive(Clone, Copy, Error, Debug, PartialEq, Eq, Hash)]
 pub enum DurationError {
@@ -465,6 +772,9 @@ pub enum DurationError {
     /// The value is out of bounds when another, subsequent value is present (i.e. `01:61:48`).
     #[error("out of bounds value when greater order value is specified")]
     TooLarge,
+    /// The frame number of a timecode is not below the frame rate.
+    #[error("frame {0} is out of bounds at {1} frames per second")]
+    FrameOutOfBounds(u32, u32),
 }
 
 impl FromStr for Duration {
@@ -595,12 +905,46 @@ fn parse_year(s: &mut Scanner) -> Result<i32, DateError> {
     parse_int(s, 4..=4).ok_or(DateError::UnknownFormat)
 }
 
-fn parse_short_year(s: &mut Scanner) -> Result<i32, DateError> {
+/// Parse a two-digit year, placing years up to `pivot` in the 2000s.
+fn parse_short_year(s: &mut Scanner, pivot: u8) -> Result<i32, DateError> {
     let year = parse_int(s, 2..=2).ok_or(DateError::UnknownFormat)?;
-    let year = if year < 50 { year + 2000 } else { year + 1900 };
+    let year = if year <= pivot as i32 { year + 2000 } else { year + 1900 };
     Ok(year)
 }
 
+/// Parse a four-digit year or, if a pivot is given, a two-digit year that
+/// may be preceded by an apostrophe.
+fn parse_any_year(s: &mut Scanner, pivot: Option<u8>) -> Result<i32, DateError> {
+    let Some(pivot) = pivot else {
+        return parse_year(s);
+    };
+
+    let idx = s.cursor();
+    parse_year(s).or_else(|_| {
+        s.jump(idx);
+        s.eat_whitespace();
+        s.eat_if('\'');
+        parse_short_year(s, pivot)
+    })
+}
+
+/// The current year in the proleptic gregorian calendar.
+fn current_year() -> i32 {
+    let secs = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs());
+
+    // Convert days since the epoch to a civil year, see
+    // http://howardhinnant.github.io/date_algorithms.html#civil_from_days
+    let days = (secs / 86_400) as i64 + 719_468;
+    let era = days.div_euclid(146_097);
+    let doe = days.rem_euclid(146_097);
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    (yoe + era * 400 + i64::from(mp >= 10)) as i32
+}
+
 fn parse_month(s: &mut Scanner) -> Result<u8, DateError> {
     let month: u8 = parse_unsigned_int(s, 1..=2).ok_or(DateError::UnknownFormat)?;
     if !(1..=12).contains(&month) {
@@ -613,14 +957,17 @@ fn parse_month(s: &mut Scanner) -> Result<u8, DateError> {
 fn parse_day(s: &mut Scanner) -> Result<u8, DateError> {
     let day: u8 = parse_unsigned_int(s, 1..=2).ok_or(DateError::UnknownFormat)?;
     if !(1..=31).contains(&day) {
-        return Err(DateError::MonthOutOfBounds);
+        return Err(DateError::DayOutOfBounds(day));
     }
 
     Ok(day - 1)
 }
 
-fn parse_month_with_year(s: &mut Scanner) -> Result<(i32, u8), DateError> {
-    let year = parse_year(s)?;
+fn parse_month_with_year(
+    s: &mut Scanner,
+    pivot: Option<u8>,
+) -> Result<(i32, u8), DateError> {
+    let year = parse_any_year(s, pivot)?;
     s.eat_whitespace();
 
     if !s.eat_if('-') {
@@ -637,11 +984,16 @@ fn parse_month_with_year(s: &mut Scanner) -> Result<(i32, u8), DateError> {
     Ok((year, month))
 }
 
-fn parse_full_date(s: &mut Scanner) -> Result<(i32, u8, u8), DateError> {
+fn parse_full_date(
+    s: &mut Scanner,
+    pivot: Option<u8>,
+) -> Result<(i32, u8, u8), DateError> {
+    // Two-digit years in full dates have always been accepted, with years
+    // below 50 being placed in the 2000s.
     let idx = s.cursor();
-    let year = parse_short_year(s).or_else(|_| {
+    let year = parse_short_year(s, pivot.unwrap_or(49)).or_else(|_| {
         s.jump(idx);
-        parse_year(s)
+        parse_any_year(s, pivot)
     })?;
 
     s.eat_whitespace();
@@ -670,6 +1022,102 @@ fn parse_full_date(s: &mut Scanner) -> Result<(i32, u8, u8), DateError> {
     Ok((year, month, day))
 }
 
+/// Parse an ISO week date like `2020-W05` or `2020-W05-3` or an ordinal date
+/// like `2020-123` into a year and zero-based month and day. Week dates
+/// without a weekday resolve to the Monday of the week.
+fn parse_week_or_ordinal_date(s: &mut Scanner) -> Result<(i32, u8, u8), DateError> {
+    let mut year = parse_year(s)?;
+    s.eat_whitespace();
+    if !s.eat_if('-') {
+        return Err(DateError::UnknownFormat);
+    }
+
+    let mut ordinal = if s.eat_if('W') {
+        let week: u8 = parse_unsigned_int(s, 2..=2).ok_or(DateError::UnknownFormat)?;
+        let weekday: u8 = if s.eat_if('-') {
+            parse_unsigned_int(s, 1..=1).ok_or(DateError::UnknownFormat)?
+        } else {
+            1
+        };
+
+        if !(1..=7).contains(&weekday) {
+            return Err(DateError::DayOfYearOutOfBounds(weekday.into()));
+        }
+
+        if week == 0 || week > weeks_in_year(year) {
+            return Err(DateError::WeekOutOfBounds(week));
+        }
+
+        // The fourth of January is always in the first week.
+        i32::from(week) * 7 + i32::from(weekday) - (weekday_of(year, 0, 3) + 3)
+    } else {
+        let ordinal: u16 =
+            parse_unsigned_int(s, 3..=3).ok_or(DateError::UnknownFormat)?;
+        if ordinal == 0 || ordinal > days_in_year(year) {
+            return Err(DateError::DayOfYearOutOfBounds(ordinal));
+        }
+
+        i32::from(ordinal)
+    };
+
+    s.eat_whitespace();
+    if !s.done() {
+        return Err(DateError::UnknownFormat);
+    }
+
+    if ordinal < 1 {
+        year -= 1;
+        ordinal += i32::from(days_in_year(year));
+    } else if ordinal > i32::from(days_in_year(year)) {
+        ordinal -= i32::from(days_in_year(year));
+        year += 1;
+    }
+
+    let mut day = ordinal - 1;
+    let mut month = 0;
+    while day >= i32::from(days_in_month(month, year)) {
+        day -= i32::from(days_in_month(month, year));
+        month += 1;
+    }
+
+    Ok((year, month, day as u8))
+}
+
+/// The number of ISO weeks in a year.
+fn weeks_in_year(year: i32) -> u8 {
+    let jan_first = weekday_of(year, 0, 0);
+    if jan_first == 4 || (jan_first == 3 && days_in_year(year) == 366) {
+        53
+    } else {
+        52
+    }
+}
+
+/// The ISO weekday (Monday is 1, Sunday is 7) of a date with zero-based month
+/// and day.
+fn weekday_of(year: i32, month: u8, day: u8) -> i32 {
+    // Convert the civil date to days since the epoch, see
+    // http://howardhinnant.github.io/date_algorithms.html#days_from_civil
+    let (m, d) = (i64::from(month) + 1, i64::from(day) + 1);
+    let y = i64::from(year) - i64::from(m <= 2);
+    let era = y.div_euclid(400);
+    let yoe = y.rem_euclid(400);
+    let doy = (153 * (m + if m > 2 { -3 } else { 9 }) + 2) / 5 + d - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    let days = era * 146_097 + doe - 719_468;
+
+    // The epoch was a Thursday.
+    ((days + 3).rem_euclid(7) + 1) as i32
+}
+
+fn days_in_year(year: i32) -> u16 {
+    if days_in_month(1, year) == 29 {
+        366
+    } else {
+        365
+    }
+}
+
 fn days_in_month(month: u8, year: i32) -> u8 {
     if month == 1 {
         if year % 4 == 0 && (year % 100 != 0 || year % 400 == 0) {
@@ -687,6 +1135,284 @@ fn days_in_month(month: u8, year: i32) -> u8 {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::types::QualifiedUrl;
+
+    #[test]
+    fn test_week_and_ordinal_dates() {
+        let date = |y, m, d| Ok(Date::new(y, Some(m), Some(d)).unwrap());
+        assert_eq!(Date::from_str("2020-W05"), date(2020, 1, 27));
+        assert_eq!(Date::from_str("2020-W05-3"), date(2020, 1, 29));
+        assert_eq!(Date::from_str("2020-W01-1"), date(2019, 12, 30));
+        assert_eq!(Date::from_str("2020-W53-7"), date(2021, 1, 3));
+        assert_eq!(Date::from_str("2021-W53"), Err(DateError::WeekOutOfBounds(53)));
+        assert_eq!(Date::from_str("2020-W00"), Err(DateError::WeekOutOfBounds(0)));
+        assert_eq!(Date::from_str("2020-W05-8"), Err(DateError::DayOfYearOutOfBounds(8)));
+
+        assert_eq!(Date::from_str("2020-123"), date(2020, 5, 2));
+        assert_eq!(Date::from_str("2020-001"), date(2020, 1, 1));
+        assert_eq!(Date::from_str("2020-366"), date(2020, 12, 31));
+        assert_eq!(Date::from_str("2021-366"), Err(DateError::DayOfYearOutOfBounds(366)));
+        assert_eq!(Date::from_str("~2020-060").map(|d| d.approximate), Ok(true));
+
+        assert_eq!(
+            Date::from_str("2020-12"),
+            Ok(Date { month: Some(11), ..Date::from_year(2020) })
+        );
+    }
+
+    #[test]
+    fn test_date_parts() {
+        let date: Date = serde_yaml::from_str("[2020, 5, 1]").unwrap();
+        assert_eq!(date, Date::new(2020, Some(5), Some(1)).unwrap());
+
+        let date: Date = serde_yaml::from_str("[2020, 5]").unwrap();
+        assert_eq!(date, Date::new(2020, Some(5), None).unwrap());
+
+        let date: Date = serde_yaml::from_str("[2020]").unwrap();
+        assert_eq!(date, Date::from_year(2020));
+
+        assert!(serde_yaml::from_str::<Date>("[2020, 13]").is_err());
+        assert!(serde_yaml::from_str::<Date>("[2021, 2, 29]").is_err());
+        assert!(serde_yaml::from_str::<Date>("[]").is_err());
+        assert!(serde_yaml::from_str::<Date>("[2020, 1, 1, 1]").is_err());
+
+        let date: Date = serde_yaml::from_str("2020-05-01").unwrap();
+        assert_eq!(date, Date::new(2020, Some(5), Some(1)).unwrap());
+        let date: Date = serde_yaml::from_str("2020").unwrap();
+        assert_eq!(date, Date::from_year(2020));
+    }
+
+    #[test]
+    fn test_date_trailing_period() {
+        assert_eq!(Date::from_str("2020.").unwrap(), Date::from_year(2020));
+        assert_eq!(Date::from_str(" 2020 ").unwrap(), Date::from_year(2020));
+        assert_eq!(Date::from_str(" 2020. ").unwrap(), Date::from_year(2020));
+        assert_eq!(
+            Date::from_str("2020-03-04.").unwrap(),
+            Date::from_str("2020-03-04").unwrap()
+        );
+        assert!(Date::from_str("2020..").is_err());
+        assert!(Date::from_str(".").is_err());
+        assert!(Date::from_str("20x0.").is_err());
+    }
+
+    #[test]
+    fn test_date_serialize() {
+        let yaml = |date: &str| serde_yaml::to_string(&Date::from_str(date).unwrap());
+        assert_eq!(yaml("2021").unwrap(), "2021\n");
+        assert_eq!(yaml("-0044").unwrap(), "-44\n");
+        assert_eq!(yaml("2021-04").unwrap(), "2021-04\n");
+        assert_eq!(yaml("2021-04-09").unwrap(), "2021-04-09\n");
+
+        let approx = Date::from_str("~2021").unwrap();
+        let yaml = serde_yaml::to_string(&approx).unwrap();
+        assert_eq!(yaml, "~2021\n");
+        assert_eq!(serde_yaml::from_str::<Date>(&yaml).unwrap(), approx);
+    }
+
+    #[test]
+    fn test_calendar_components() {
+        let date = Date::from_str("2021-12-31").unwrap();
+        assert_eq!(date.year, 2021);
+        assert_eq!(date.calendar_month(), Some(12));
+        assert_eq!(date.calendar_day(), Some(31));
+        assert_eq!(date.month, Some(11));
+        assert_eq!(date.day, Some(30));
+
+        let year = Date::from_str("2021").unwrap();
+        assert_eq!(year.year, 2021);
+        assert_eq!(year.calendar_month(), None);
+        assert_eq!(year.calendar_day(), None);
+        assert_eq!(Date::from_str("2021-02").unwrap().calendar_day(), None);
+
+        let overflow = Date {
+            year: 2021,
+            month: Some(255),
+            day: Some(255),
+            approximate: false,
+        };
+        assert_eq!(overflow.calendar_month(), Some(256));
+        assert_eq!(overflow.calendar_day(), Some(256));
+    }
+
+    #[test]
+    fn test_malformed_dates() {
+        let malformed = [
+            "",
+            " ",
+            "~",
+            "-",
+            "--",
+            "-W",
+            "2020-",
+            "2020--05",
+            "2020-05-",
+            "2020-05-01T23:59:60",
+            "2020-05-01 23:59:60",
+            "2020-05-01T24:00:00Z",
+            "2016-12-31 23:59:60.5",
+            "2020-00-10",
+            "2020-13-01",
+            "2020-99-99",
+            "2020-05-00",
+            "2020-05-32",
+            "2020-05-99",
+            "2021-02-29",
+            "2020-1-1-1",
+            "99999-01-01",
+            "20200-W01",
+            "2020-W",
+            "2020-W99",
+            "2020-W01-0",
+            "2020-W01-9",
+            "2020-000",
+            "2020-999",
+            "2020-\u{0663}\u{0663}",
+            "\u{0662}\u{0660}\u{0662}\u{0660}",
+            "2020-05-\u{0661}",
+            "+-2020",
+            "~~2020",
+        ];
+
+        for source in malformed {
+            assert!(Date::from_str(source).is_err(), "{source:?}");
+            assert!(Date::from_str_with_pivot(source, 30).is_err(), "{source:?}");
+        }
+
+        assert_eq!(Date::from_str("2020-05-32"), Err(DateError::DayOutOfBounds(32)));
+        assert_eq!(Date::from_str("2020-02-30"), Err(DateError::DayOutOfBounds(30)));
+
+        for yaml in [
+            "{year: 2020, month: 12}",
+            "{year: 2020, month: 255, day: 255}",
+            "{year: 2020, day: 3}",
+            "{year: 2020, month: 1, day: 29}",
+            "{year: 2020, month: 0, day: 255}",
+            "{month: 1}",
+            "[2020, 255, 255]",
+            "[2020, 0]",
+            "[2020, 1, 0]",
+            "99999999999",
+        ] {
+            assert!(serde_yaml::from_str::<Date>(yaml).is_err(), "{yaml}");
+        }
+
+        for yaml in [
+            "{value: https://example.com, date: 2020-05-01T23:59:60}",
+            "{value: https://example.com, date: 2020-13-01}",
+            "{value: https://example.com, date: {year: 2020, month: 255}}",
+        ] {
+            assert!(serde_yaml::from_str::<QualifiedUrl>(yaml).is_err(), "{yaml}");
+        }
+
+        let url: QualifiedUrl =
+            serde_yaml::from_str("{value: https://example.com, date: 2020-05-01}")
+                .unwrap();
+        assert_eq!(url.visit_date, Some(Date::new(2020, Some(5), Some(1)).unwrap()));
+
+        let date: Date = serde_yaml::from_str("{year: 2020, month: 1, day: 28}").unwrap();
+        assert_eq!(date, Date::new(2020, Some(2), Some(29)).unwrap());
+    }
+
+    #[test]
+    fn test_parse_flexible() {
+        let us: LanguageIdentifier = "en-US".parse().unwrap();
+        let gb: LanguageIdentifier = "en-GB".parse().unwrap();
+        let de: LanguageIdentifier = "de".parse().unwrap();
+        let date = |y, m, d| Ok(Date::new(y, Some(m), Some(d)).unwrap());
+
+        assert_eq!(Date::parse_flexible("01/05/2020", &us), date(2020, 1, 5));
+        assert_eq!(Date::parse_flexible("01/05/2020", &gb), date(2020, 5, 1));
+        assert_eq!(Date::parse_flexible("1.5.2020", &de), date(2020, 5, 1));
+        assert_eq!(Date::parse_flexible("2020/05/01", &us), date(2020, 5, 1));
+        assert_eq!(Date::parse_flexible("2020-05-01", &gb), date(2020, 5, 1));
+        assert_eq!(Date::parse_flexible("2020", &gb), Ok(Date::from_year(2020)));
+
+        assert_eq!(
+            Date::parse_flexible("13/05/2020", &us),
+            Err(DateError::MonthOutOfBounds)
+        );
+        assert_eq!(
+            Date::parse_flexible("31/02/2020", &gb),
+            Err(DateError::DayOutOfBounds(31))
+        );
+        assert!(Date::parse_flexible("01/05/20", &gb).is_err());
+        assert!(Date::parse_flexible("01/05", &gb).is_err());
+        assert!(Date::parse_flexible("a/b/2020", &gb).is_err());
+        assert!(Date::from_str("01/05/2020").is_err());
+    }
+
+    #[test]
+    fn test_years_since() {
+        let date = |s| Date::from_str(s).unwrap();
+        assert_eq!(date("2020-06-15").years_since(&date("1990-06-15")), Some(30));
+        assert_eq!(date("2020-06-14").years_since(&date("1990-06-15")), Some(29));
+        assert_eq!(date("1990-06-15").years_since(&date("2020-06-14")), Some(-29));
+        assert_eq!(date("1990-06-15").years_since(&date("2020-06-15")), Some(-30));
+        assert_eq!(date("2020-06").years_since(&date("1990-06-15")), Some(30));
+        assert_eq!(date("2020-05").years_since(&date("1990-06-15")), Some(29));
+        assert_eq!(date("2020").years_since(&date("1990-12-31")), Some(30));
+        assert_eq!(date("2020").years_since(&date("1990")), Some(30));
+        assert_eq!(date("1990").years_since(&date("2020")), Some(-30));
+        assert_eq!(date("2020-02-03").years_since(&date("2020-01-01")), Some(0));
+    }
+
+    #[test]
+    fn test_display_with_certainty() {
+        let locales = crate::archive::locales();
+        let locale = |code: &str| {
+            locales
+                .iter()
+                .find(|l| l.lang.as_ref().is_some_and(|l| l.0 == code))
+                .unwrap()
+        };
+        let en = locale("en-US");
+        let approximate = Date::from_str("~2020").unwrap();
+        let exact = Date::from_str("2020-05-01").unwrap();
+
+        assert_eq!(approximate.display_with_certainty(en), "c. 2020");
+        assert_eq!(approximate.display_with_certainty(locale("de-DE")), "ca. 2020");
+        assert_eq!(exact.display_with_certainty(en), "2020");
+        let bce = Date { approximate: true, ..Date::from_year(-50) };
+        assert_eq!(bce.display_with_certainty(en), "c. 51 BCE");
+    }
+
+    #[test]
+    fn test_new() {
+        let leap = Date::new(2024, Some(2), Some(29)).unwrap();
+        assert_eq!((leap.year, leap.month, leap.day), (2024, Some(1), Some(28)));
+        assert_eq!(
+            Date::new(2023, Some(2), Some(29)),
+            Err(DateError::DayOutOfBounds(29))
+        );
+        assert_eq!(
+            Date::new(2024, Some(2), Some(30)),
+            Err(DateError::DayOutOfBounds(30))
+        );
+        assert_eq!(Date::new(2024, Some(13), None), Err(DateError::MonthOutOfBounds));
+        assert_eq!(Date::new(2024, None, Some(3)), Err(DateError::DayWithoutMonth));
+        assert_eq!(Date::new(2024, None, None), Ok(Date::from_year(2024)));
+        assert_eq!(Date::new(2024, Some(5), Some(1)), Date::from_str("2024-05-01"));
+    }
+
+    #[test]
+    fn test_two_digit_year() {
+        assert_eq!(Date::from_str_with_pivot("99", 30), Ok(Date::from_year(1999)));
+        assert_eq!(Date::from_str_with_pivot("'99", 30), Ok(Date::from_year(1999)));
+        assert_eq!(Date::from_str_with_pivot("05", 30), Ok(Date::from_year(2005)));
+        assert_eq!(Date::from_str_with_pivot("30", 30), Ok(Date::from_year(2030)));
+        assert_eq!(Date::from_str_with_pivot("31", 30), Ok(Date::from_year(1931)));
+        assert_eq!(Date::from_str_with_pivot("0099", 30), Ok(Date::from_year(99)));
+
+        let date = Date::from_str_with_pivot("98-04", 30).unwrap();
+        assert_eq!((date.year, date.month), (1998, Some(3)));
+        let date = Date::from_str_with_pivot("40-04-01", 50).unwrap();
+        assert_eq!((date.year, date.month, date.day), (2040, Some(3), Some(0)));
+
+        // The default parser keeps requiring four-digit years.
+        assert!(Date::from_str("99").is_err());
+        assert!(Date::default_year_pivot() < 100);
+    }
 
     #[test]
     fn test_duration_parse() {
@@ -734,6 +1460,84 @@ mod tests {
         assert!(Duration::from_str("010:00,").is_err());
     }
 
+    #[test]
+    fn test_duration_seconds() {
+        let duration = Duration::from_str("1:02:03:04,567").unwrap();
+        assert_eq!(duration.as_seconds(), 93784.567);
+        assert_eq!(Duration::from_seconds(duration.as_seconds()), duration);
+
+        assert_eq!(
+            Duration::from_seconds(61.0015),
+            Duration {
+                minutes: 1,
+                seconds: 1,
+                milliseconds: 2,
+                ..Default::default()
+            }
+        );
+        assert_eq!(Duration::from_seconds(-5.0), Duration::default());
+        assert_eq!(Duration::from_seconds(f64::NAN), Duration::default());
+    }
+
+    #[test]
+    fn test_duration_sum() {
+        let parts =
+            ["45:30", "52:10", "01:10:20,500"].map(|s| Duration::from_str(s).unwrap());
+        assert_eq!(
+            parts.iter().copied().sum::<Duration>(),
+            Duration::from_str("02:48:00,500").unwrap()
+        );
+        assert_eq!(parts.iter().max(), Some(&parts[2]));
+        assert_eq!(parts.iter().min(), Some(&parts[0]));
+        assert_eq!(std::iter::empty().sum::<Duration>(), Duration::default());
+        assert_eq!(
+            Duration::from_str("23:30:00").unwrap()
+                + Duration::from_str("01:00:00").unwrap(),
+            Duration::from_str("1:00:30:00").unwrap()
+        );
+    }
+
+    #[test]
+    fn test_duration_timecode() {
+        assert_eq!(
+            Duration::from_timecode("01:02:33:12", 24).unwrap(),
+            Duration {
+                days: 0,
+                hours: 1,
+                minutes: 2,
+                seconds: 33,
+                milliseconds: 500,
+            }
+        );
+        assert_eq!(
+            Duration::from_timecode("01:02:33:12", 25).unwrap(),
+            Duration {
+                days: 0,
+                hours: 1,
+                minutes: 2,
+                seconds: 33,
+                milliseconds: 480,
+            }
+        );
+        assert_eq!(Duration::from_timecode("00:00:00:23", 24).unwrap().milliseconds, 958);
+        assert_eq!(Duration::from_timecode("26:00:00:00", 25).unwrap().days, 1);
+        assert_eq!(
+            Duration::from_timecode("00:00:01:24", 24),
+            Err(DurationError::FrameOutOfBounds(24, 24))
+        );
+        assert_eq!(
+            Duration::from_timecode("00:00:01:25", 25),
+            Err(DurationError::FrameOutOfBounds(25, 25))
+        );
+        assert!(Duration::from_timecode("00:00:01:24", 25).is_ok());
+        assert_eq!(
+            Duration::from_timecode("00:61:00:00", 24),
+            Err(DurationError::TooLarge)
+        );
+        assert!(Duration::from_timecode("00:01:00", 24).is_err());
+        assert!(Duration::from_timecode("00:00:01:00", 0).is_err());
+    }
+
     #[test]
     fn test_duration_range_parse() {
         assert_eq!(
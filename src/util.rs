@@ -2,6 +2,13 @@
 
 use serde::{de::Visitor, Deserialize, Deserializer, Serialize};
 
+use crate::types::{
+    FormatString, MaybeTyped, Numeric, Person, PersonRole, PersonsWithRoles, QualifiedUrl,
+};
+
+/// Label prefixes that are dropped from page ranges and volumes.
+const LOCATOR_LABELS: &[&str] = &["p.", "pp.", "S."];
+
 /// Generic wrapper that allow one or more occurrences of specified type.
 ///
 /// In YAML it will presented or as a value, or as an array:
@@ -185,3 +192,441 @@ where
 {
     <Option<MapOneOrMany<T>>>::deserialize(deserializer).map(|v| v.map(|v| v.into()))
 }
+
+/// Deserialize optional tags. Like keywords, a string may hold several tags
+/// separated by commas.
+pub fn deserialize_tags_opt<'de, D>(
+    deserializer: D,
+) -> Result<Option<Vec<String>>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let tags: Option<Vec<String>> = deserialize_one_or_many_opt(deserializer)?;
+    Ok(tags
+        .map(|tags| {
+            tags.iter()
+                .flat_map(|t| t.split(','))
+                .map(str::trim)
+                .filter(|t| !t.is_empty())
+                .map(ToString::to_string)
+                .collect::<Vec<_>>()
+        })
+        .filter(|tags| !tags.is_empty()))
+}
+
+/// Deserialize an optional page range or volume, dropping label prefixes like
+/// `pp.` that are often pasted along with the number.
+pub fn deserialize_locator_opt<'de, D>(
+    deserializer: D,
+) -> Result<Option<MaybeTyped<Numeric>>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let mut value = <Option<MaybeTyped<Numeric>>>::deserialize(deserializer)?;
+    if let Some(MaybeTyped::Typed(numeric)) = &mut value {
+        if numeric
+            .prefix
+            .as_deref()
+            .is_some_and(|p| LOCATOR_LABELS.contains(&p.trim_end()))
+        {
+            numeric.prefix = None;
+        }
+    }
+
+    Ok(value)
+}
+
+/// Deserialize an optional list of persons. A single string may contain
+/// multiple persons separated by semicolons.
+pub fn deserialize_persons_opt<'de, D>(
+    deserializer: D,
+) -> Result<Option<Vec<Person>>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    struct PersonsVisitor;
+
+    impl<'de> Visitor<'de> for PersonsVisitor {
+        type Value = Vec<Person>;
+
+        fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+            formatter.write_str("a map, a string, or a list")
+        }
+
+        fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+        where
+            E: serde::de::Error,
+        {
+            v.split(';')
+                .map(str::trim)
+                .filter(|s| !s.is_empty())
+                .map(|s| Person::deserialize(serde::de::value::StrDeserializer::new(s)))
+                .collect()
+        }
+
+        fn visit_map<A>(self, map: A) -> Result<Self::Value, A::Error>
+        where
+            A: serde::de::MapAccess<'de>,
+        {
+            Ok(vec![Person::deserialize(serde::de::value::MapAccessDeserializer::new(
+                map,
+            ))?])
+        }
+
+        fn visit_seq<A>(self, seq: A) -> Result<Self::Value, A::Error>
+        where
+            A: serde::de::SeqAccess<'de>,
+        {
+            Vec::deserialize(serde::de::value::SeqAccessDeserializer::new(seq))
+        }
+    }
+
+    struct PersonsOpt(Vec<Person>);
+
+    impl<'de> Deserialize<'de> for PersonsOpt {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            deserializer.deserialize_any(PersonsVisitor).map(PersonsOpt)
+        }
+    }
+
+    Ok(<Option<PersonsOpt>>::deserialize(deserializer)?
+        .map(|v| v.0)
+        .filter(|persons| !persons.is_empty()))
+}
+
+/// Serialize an optional list of formattable strings like
+/// [`serialize_one_or_many_opt`]. A single value that contains a semicolon
+/// is written as a list so that it is not split when it is read back.
+pub fn serialize_semicolon_list_opt<S>(
+    value: &Option<Vec<FormatString>>,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    S: serde::Serializer,
+{
+    match value.as_deref() {
+        Some([single]) => {
+            let mut buf = String::new();
+            single
+                .value
+                .fmt_serialized(&mut buf)
+                .map_err(|_| serde::ser::Error::custom("could not write to string"))?;
+            if split_semicolons(&buf).len() > 1 {
+                [single].serialize(serializer)
+            } else {
+                single.serialize(serializer)
+            }
+        }
+        _ => serialize_one_or_many_opt(value, serializer),
+    }
+}
+
+/// Split a serialized formattable string at its semicolons. Semicolons that
+/// are escaped or inside braces or math do not separate values.
+fn split_semicolons(s: &str) -> Vec<&str> {
+    let mut parts = vec![];
+    let mut start = 0;
+    let mut depth = 0usize;
+    let mut math = false;
+    let mut chars = s.char_indices();
+    while let Some((i, c)) = chars.next() {
+        match c {
+            '\\' => {
+                chars.next();
+            }
+            '$' => math = !math,
+            '{' if !math => depth += 1,
+            '}' if !math => depth = depth.saturating_sub(1),
+            ';' if !math && depth == 0 => {
+                parts.push(&s[start..i]);
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+
+    parts.push(&s[start..]);
+    parts
+}
+
+/// Deserialize an optional list of formattable strings. A single string may
+/// contain multiple values separated by semicolons outside of braces.
+pub fn deserialize_semicolon_list_opt<'de, D>(
+    deserializer: D,
+) -> Result<Option<Vec<FormatString>>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    struct ListVisitor;
+
+    impl<'de> Visitor<'de> for ListVisitor {
+        type Value = Vec<FormatString>;
+
+        fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+            formatter.write_str("a map, a string, or a list")
+        }
+
+        fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+        where
+            E: serde::de::Error,
+        {
+            split_semicolons(v)
+                .into_iter()
+                .map(str::trim)
+                .filter(|s| !s.is_empty())
+                .map(|s| {
+                    FormatString::deserialize(serde::de::value::StrDeserializer::new(s))
+                })
+                .collect()
+        }
+
+        fn visit_map<A>(self, map: A) -> Result<Self::Value, A::Error>
+        where
+            A: serde::de::MapAccess<'de>,
+        {
+            Ok(vec![FormatString::deserialize(
+                serde::de::value::MapAccessDeserializer::new(map),
+            )?])
+        }
+
+        fn visit_seq<A>(self, seq: A) -> Result<Self::Value, A::Error>
+        where
+            A: serde::de::SeqAccess<'de>,
+        {
+            Vec::deserialize(serde::de::value::SeqAccessDeserializer::new(seq))
+        }
+    }
+
+    struct ListOpt(Vec<FormatString>);
+
+    impl<'de> Deserialize<'de> for ListOpt {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            deserializer.deserialize_any(ListVisitor).map(ListOpt)
+        }
+    }
+
+    <Option<ListOpt>>::deserialize(deserializer).map(|v| v.map(|v| v.0))
+}
+
+/// Deserialize optional groups of persons with roles. Besides a single group
+/// or a list of groups, this accepts a map from roles to persons.
+pub fn deserialize_affiliated_opt<'de, D>(
+    deserializer: D,
+) -> Result<Option<Vec<PersonsWithRoles>>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    struct AffiliatedVisitor;
+
+    impl<'de> Visitor<'de> for AffiliatedVisitor {
+        type Value = Vec<PersonsWithRoles>;
+
+        fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+            formatter.write_str("a map or a list")
+        }
+
+        fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+        where
+            A: serde::de::MapAccess<'de>,
+        {
+            let mut names = None;
+            let mut role = None;
+            let mut groups = vec![];
+            while let Some(key) = map.next_key::<String>()? {
+                match key.as_str() {
+                    "names" => {
+                        names = Some(map.next_value::<MapOneOrMany<Person>>()?.into())
+                    }
+                    "role" => role = Some(map.next_value::<PersonRole>()?),
+                    key => {
+                        let role = PersonRole::deserialize(
+                            serde::de::value::StrDeserializer::<A::Error>::new(key),
+                        )?;
+                        let names = map.next_value::<MapOneOrMany<Person>>()?.into();
+                        groups.push(PersonsWithRoles::new(names, role));
+                    }
+                }
+            }
+
+            match (names, role) {
+                (None, None) => Ok(groups),
+                (Some(names), Some(role)) if groups.is_empty() => {
+                    Ok(vec![PersonsWithRoles::new(names, role)])
+                }
+                (None, _) if groups.is_empty() => {
+                    Err(serde::de::Error::missing_field("names"))
+                }
+                (_, None) if groups.is_empty() => {
+                    Err(serde::de::Error::missing_field("role"))
+                }
+                _ => Err(serde::de::Error::custom(
+                    "cannot mix `names` and `role` with roles as keys",
+                )),
+            }
+        }
+
+        fn visit_seq<A>(self, seq: A) -> Result<Self::Value, A::Error>
+        where
+            A: serde::de::SeqAccess<'de>,
+        {
+            Vec::deserialize(serde::de::value::SeqAccessDeserializer::new(seq))
+        }
+    }
+
+    struct AffiliatedOpt(Vec<PersonsWithRoles>);
+
+    impl<'de> Deserialize<'de> for AffiliatedOpt {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            deserializer.deserialize_any(AffiliatedVisitor).map(AffiliatedOpt)
+        }
+    }
+
+    <Option<AffiliatedOpt>>::deserialize(deserializer).map(|v| v.map(|v| v.0))
+}
+
+/// Deserialize an optional list of URLs. The errors of list elements name
+/// their index.
+pub fn deserialize_urls_opt<'de, D>(
+    deserializer: D,
+) -> Result<Option<Vec<QualifiedUrl>>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    struct UrlsVisitor;
+
+    impl<'de> Visitor<'de> for UrlsVisitor {
+        type Value = Vec<QualifiedUrl>;
+
+        fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+            formatter.write_str("a map, a string, or a list")
+        }
+
+        fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+        where
+            E: serde::de::Error,
+        {
+            Ok(vec![QualifiedUrl::deserialize(serde::de::value::StrDeserializer::new(
+                v,
+            ))?])
+        }
+
+        fn visit_map<A>(self, map: A) -> Result<Self::Value, A::Error>
+        where
+            A: serde::de::MapAccess<'de>,
+        {
+            Ok(vec![QualifiedUrl::deserialize(
+                serde::de::value::MapAccessDeserializer::new(map),
+            )?])
+        }
+
+        fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+        where
+            A: serde::de::SeqAccess<'de>,
+        {
+            let mut urls = Vec::with_capacity(seq.size_hint().unwrap_or(0).min(16));
+            loop {
+                let index = urls.len();
+                match seq.next_element::<QualifiedUrl>() {
+                    Ok(Some(url)) => urls.push(url),
+                    Ok(None) => return Ok(urls),
+                    Err(err) => {
+                        return Err(serde::de::Error::custom(format!(
+                            "invalid url at index {}: {}",
+                            index, err
+                        )))
+                    }
+                }
+            }
+        }
+    }
+
+    struct Urls(Vec<QualifiedUrl>);
+
+    impl<'de> Deserialize<'de> for Urls {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            deserializer.deserialize_any(UrlsVisitor).map(Urls)
+        }
+    }
+
+    <Option<Urls>>::deserialize(deserializer).map(|v| v.map(|v| v.0))
+}
+
+/// A title that may be followed by its translation, written as a sequence of
+/// the original and the translated title.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TitleWithTranslation {
+    /// The title in the original language.
+    pub title: FormatString,
+    /// The translated title, if any.
+    pub translated: Option<FormatString>,
+}
+
+impl<'de> Deserialize<'de> for TitleWithTranslation {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct TitleVisitor;
+
+        impl<'de> Visitor<'de> for TitleVisitor {
+            type Value = TitleWithTranslation;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+                formatter.write_str(
+                    "a formattable string or a list of a title and its translation",
+                )
+            }
+
+            fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                let title =
+                    FormatString::deserialize(serde::de::value::StrDeserializer::new(v))?;
+                Ok(TitleWithTranslation { title, translated: None })
+            }
+
+            fn visit_map<A>(self, map: A) -> Result<Self::Value, A::Error>
+            where
+                A: serde::de::MapAccess<'de>,
+            {
+                let title = FormatString::deserialize(
+                    serde::de::value::MapAccessDeserializer::new(map),
+                )?;
+                Ok(TitleWithTranslation { title, translated: None })
+            }
+
+            fn visit_seq<A>(self, seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: serde::de::SeqAccess<'de>,
+            {
+                let titles = Vec::<FormatString>::deserialize(
+                    serde::de::value::SeqAccessDeserializer::new(seq),
+                )?;
+                let mut titles = titles.into_iter();
+                match (titles.next(), titles.next(), titles.len()) {
+                    (Some(title), translated, 0) => {
+                        Ok(TitleWithTranslation { title, translated })
+                    }
+                    _ => Err(serde::de::Error::custom(
+                        "expected a title and at most one translation",
+                    )),
+                }
+            }
+        }
+
+        deserializer.deserialize_any(TitleVisitor)
+    }
+}
@@ -0,0 +1,597 @@
+//! Checks for entries that parse fine but are likely incomplete or redundant.
+
+use std::collections::HashMap;
+use std::fmt;
+
+use crate::types::{EntryType, PublicationStatus};
+use crate::{Entry, Library};
+
+/// A problem with an entry that does not prevent it from being used.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ValidationWarning {
+    /// The `url` only links to the DOI resolver for the entry's `doi`.
+    RedundantDoiUrl,
+    /// A `doi` does not have the form `10.<registrant>/<suffix>`.
+    MalformedDoi(String),
+    /// An `isbn` has the wrong length or check digit.
+    InvalidIsbn(String),
+    /// An `issn` has the wrong length or check digit.
+    InvalidIssn(String),
+    /// An audio or video entry does not specify its `medium`.
+    MissingMedium,
+    /// A paper in conference proceedings does not name the `conference`.
+    MissingConference,
+    /// The entry has no fields of its own apart from its `parent`.
+    OrphanParentOnly,
+    /// A parent of the entry has no fields and no parents itself, like
+    /// `parent: {}`.
+    EmptyParent,
+    /// The entry is usually part of a larger work but has no parent.
+    MissingParent {
+        /// The type of parent that was expected.
+        expected: EntryType,
+    },
+    /// None of the parents has a type that could contain the entry.
+    MismatchedParent {
+        /// The type of parent that was expected.
+        expected: EntryType,
+    },
+    /// The entry is retracted, but none of its notes mentions the
+    /// retraction.
+    RetractionWithoutNote,
+    /// Another entry of the library has the same title, ignoring case,
+    /// whitespace, and punctuation.
+    DuplicateTitle {
+        /// The key of the first other entry with this title.
+        other: String,
+    },
+}
+
+impl fmt::Display for ValidationWarning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::RedundantDoiUrl => {
+                write!(f, "the url points to the doi resolver for the doi field")
+            }
+            Self::MalformedDoi(doi) => write!(f, "`{}` is not a valid doi", doi),
+            Self::InvalidIsbn(isbn) => write!(f, "`{}` is not a valid isbn", isbn),
+            Self::InvalidIssn(issn) => write!(f, "`{}` is not a valid issn", issn),
+            Self::MissingMedium => {
+                write!(f, "audio and video entries should have a medium")
+            }
+            Self::MissingConference => {
+                write!(f, "conference papers should name the conference")
+            }
+            Self::OrphanParentOnly => {
+                write!(f, "the entry has no fields apart from its parent")
+            }
+            Self::EmptyParent => write!(f, "a parent of the entry is empty"),
+            Self::MissingParent { expected } => {
+                write!(f, "the entry should have a parent of type {:?}", expected)
+            }
+            Self::MismatchedParent { expected } => {
+                write!(f, "no parent of the entry fits, expected type {:?}", expected)
+            }
+            Self::RetractionWithoutNote => {
+                write!(f, "retracted entries should explain the retraction in a note")
+            }
+            Self::DuplicateTitle { other } => {
+                write!(f, "the entry has the same title as `{}`", other)
+            }
+        }
+    }
+}
+
+impl Entry {
+    /// Check the entry for likely mistakes and redundancies.
+    pub fn validate(&self) -> Vec<ValidationWarning> {
+        let mut warnings = Vec::new();
+
+        if self.has_redundant_doi_url() {
+            warnings.push(ValidationWarning::RedundantDoiUrl);
+        }
+
+        for doi in self.dois() {
+            if !is_doi(doi) {
+                warnings.push(ValidationWarning::MalformedDoi(doi.clone()));
+            }
+        }
+
+        for isbn in self.isbns() {
+            if !is_isbn(isbn) {
+                warnings.push(ValidationWarning::InvalidIsbn(isbn.clone()));
+            }
+        }
+
+        for issn in self.issns() {
+            if !is_issn(issn) {
+                warnings.push(ValidationWarning::InvalidIssn(issn.clone()));
+            }
+        }
+
+        if matches!(self.entry_type(), EntryType::Audio | EntryType::Video)
+            && self.medium().is_none()
+        {
+            warnings.push(ValidationWarning::MissingMedium);
+        }
+
+        if select!(Article > Proceedings).matches(self)
+            && self.map(|e| e.conference()).is_none()
+        {
+            warnings.push(ValidationWarning::MissingConference);
+        }
+
+        if !self.has_own_fields() && !self.parents().is_empty() {
+            warnings.push(ValidationWarning::OrphanParentOnly);
+        }
+
+        if self
+            .parents()
+            .iter()
+            .any(|p| !p.has_own_fields() && p.parents().is_empty())
+        {
+            warnings.push(ValidationWarning::EmptyParent);
+        }
+
+        if let Some(expected) = self.entry_type().default_parent_type() {
+            if self.parents().is_empty() {
+                warnings.push(ValidationWarning::MissingParent { expected });
+            } else if !self
+                .parents()
+                .iter()
+                .any(|p| can_contain(*p.entry_type(), *self.entry_type()))
+            {
+                warnings.push(ValidationWarning::MismatchedParent { expected });
+            }
+        }
+
+        if self.status() == Some(&PublicationStatus::Retracted)
+            && !self
+                .notes()
+                .unwrap_or_default()
+                .iter()
+                .any(|note| note.value.to_str().to_lowercase().contains("retract"))
+        {
+            warnings.push(ValidationWarning::RetractionWithoutNote);
+        }
+
+        warnings
+    }
+
+    /// Whether the `url` field just resolves one of the `doi`s.
+    fn has_redundant_doi_url(&self) -> bool {
+        let Some(url) = self.url() else {
+            return false;
+        };
+
+        let is_resolver = matches!(url.value.host_str(), Some("doi.org" | "dx.doi.org"));
+        let path = url.value.path().trim_start_matches('/');
+        let path = percent_decode(path);
+        is_resolver && self.dois().iter().any(|doi| path.eq_ignore_ascii_case(doi.trim()))
+    }
+}
+
+impl Library {
+    /// Check all entries for likely mistakes and redundancies, including
+    /// problems that involve multiple entries. Each warning is paired with the
+    /// key of its entry.
+    pub fn validate_all(&self) -> Vec<(String, ValidationWarning)> {
+        let mut titles: HashMap<String, Vec<&str>> = HashMap::new();
+        for entry in self.iter() {
+            if let Some(title) = entry.normalized_title() {
+                titles.entry(title).or_default().push(entry.key());
+            }
+        }
+
+        let mut warnings = Vec::new();
+        for entry in self.iter() {
+            let key = entry.key();
+            warnings.extend(entry.validate().into_iter().map(|w| (key.to_string(), w)));
+
+            let other = entry
+                .normalized_title()
+                .and_then(|title| titles[&title].iter().find(|&&k| k != key).copied());
+            if let Some(other) = other {
+                warnings.push((
+                    key.to_string(),
+                    ValidationWarning::DuplicateTitle { other: other.to_string() },
+                ));
+            }
+        }
+
+        warnings
+    }
+}
+
+/// Whether a string has the form `10.<registrant>/<suffix>` of a DOI.
+fn is_doi(doi: &str) -> bool {
+    let Some((registrant, suffix)) = doi.trim().split_once('/') else {
+        return false;
+    };
+
+    registrant.strip_prefix("10.").is_some_and(|code| {
+        !code.is_empty() && code.split('.').all(|p| p.bytes().all(|b| b.is_ascii_digit()))
+    }) && !suffix.is_empty()
+}
+
+/// The digits of an ISBN or ISSN without hyphens and spaces. A trailing `X`
+/// stands for ten. Returns `None` for any other character.
+fn check_digits(number: &str) -> Option<Vec<u32>> {
+    let chars: Vec<char> = number.chars().filter(|c| !matches!(c, '-' | ' ')).collect();
+    chars
+        .iter()
+        .enumerate()
+        .map(|(i, &c)| match c {
+            'X' | 'x' if i + 1 == chars.len() => Some(10),
+            c => c.to_digit(10),
+        })
+        .collect()
+}
+
+/// Whether an ISBN-10 or ISBN-13 has a valid check digit.
+fn is_isbn(isbn: &str) -> bool {
+    let Some(digits) = check_digits(isbn) else { return false };
+    match digits.len() {
+        10 => weighted_sum(&digits, (1..=10).rev()) % 11 == 0,
+        13 => {
+            !digits.contains(&10)
+                && weighted_sum(&digits, [1, 3].into_iter().cycle()) % 10 == 0
+        }
+        _ => false,
+    }
+}
+
+/// Whether an ISSN has a valid check digit.
+fn is_issn(issn: &str) -> bool {
+    check_digits(issn).is_some_and(|digits| {
+        digits.len() == 8 && weighted_sum(&digits, (1..=8).rev()) % 11 == 0
+    })
+}
+
+/// Sum the digits multiplied with their weights.
+fn weighted_sum(digits: &[u32], weights: impl Iterator<Item = u32>) -> u32 {
+    digits.iter().zip(weights).map(|(d, w)| d * w).sum()
+}
+
+/// Whether an entry of the `parent` type can contain an entry of the `child`
+/// type, e.g. conference papers are articles in proceedings.
+fn can_contain(parent: EntryType, child: EntryType) -> bool {
+    use EntryType::*;
+
+    child.default_parent_type() == Some(parent)
+        || matches!(
+            (child, parent),
+            (Article, Newspaper | Proceedings | Blog | Web)
+                | (Chapter, Anthology | Proceedings | Reference)
+                | (Entry, Web)
+                | (Anthos, Book)
+                | (Scene, Audio | Performance)
+        )
+}
+
+/// Decode percent-encoded bytes in a URL path.
+fn percent_decode(s: &str) -> String {
+    let mut bytes = Vec::with_capacity(s.len());
+    let mut iter = s.bytes();
+    while let Some(b) = iter.next() {
+        if b == b'%' {
+            let hex = iter.clone().take(2).collect::<Vec<_>>();
+            if let Some(decoded) = std::str::from_utf8(&hex)
+                .ok()
+                .and_then(|h| u8::from_str_radix(h, 16).ok())
+                .filter(|_| hex.len() == 2)
+            {
+                bytes.push(decoded);
+                iter.nth(1);
+                continue;
+            }
+        }
+
+        bytes.push(b);
+    }
+
+    String::from_utf8_lossy(&bytes).into_owned()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::io::from_yaml_str;
+    use crate::types::{Date, FormatString};
+
+    #[test]
+    fn validate_all() {
+        let yaml = r#"
+first:
+    type: Book
+    title: The  Odyssey
+second:
+    type: Book
+    title: the odyssey
+    serial-number:
+        doi: 10.abc/1
+film:
+    type: Video
+    title: Ulysses
+"#;
+
+        let entries = from_yaml_str(yaml).unwrap();
+        assert_eq!(
+            entries.validate_all(),
+            [
+                (
+                    "first".to_string(),
+                    ValidationWarning::DuplicateTitle { other: "second".into() }
+                ),
+                (
+                    "second".to_string(),
+                    ValidationWarning::MalformedDoi("10.abc/1".into())
+                ),
+                (
+                    "second".to_string(),
+                    ValidationWarning::DuplicateTitle { other: "first".into() }
+                ),
+                ("film".to_string(), ValidationWarning::MissingMedium),
+            ]
+        );
+        assert!(Library::new().validate_all().is_empty());
+    }
+
+    #[test]
+    fn redundant_doi_url() {
+        let yaml = r#"
+redundant:
+    type: Article
+    serial-number:
+        doi: 10.1007/BF02731494
+    url: https://doi.org/10.1007/bf02731494
+    parent:
+        title: Il Nuovo Cimento
+distinct:
+    type: Article
+    serial-number:
+        doi: 10.1007/BF02731494
+    url: https://link.springer.com/article/10.1007/BF02731494
+    parent:
+        title: Il Nuovo Cimento
+"#;
+
+        let entries = from_yaml_str(yaml).unwrap();
+        assert_eq!(
+            entries.get("redundant").unwrap().validate(),
+            [ValidationWarning::RedundantDoiUrl]
+        );
+        assert!(entries.get("distinct").unwrap().validate().is_empty());
+    }
+
+    #[test]
+    fn doi_list() {
+        let yaml = r#"
+versions:
+    type: Article
+    title: A Preprint
+    serial-number:
+        doi: [10.48550/arXiv.1706.03762, 10.5555/3295222.3295349]
+    url: https://doi.org/10.5555/3295222.3295349
+    parent:
+        type: Proceedings
+        title: NeurIPS
+        conference: NeurIPS
+malformed:
+    type: Book
+    title: A Book
+    serial-number:
+        doi: [10.1000/182, doi:10.1000/183, 10.abc/184]
+"#;
+
+        let entries = from_yaml_str(yaml).unwrap();
+        let versions = entries.get("versions").unwrap();
+        assert_eq!(versions.doi(), Some("10.48550/arXiv.1706.03762"));
+        assert_eq!(
+            versions.dois(),
+            ["10.48550/arXiv.1706.03762", "10.5555/3295222.3295349"]
+        );
+        assert_eq!(versions.validate(), [ValidationWarning::RedundantDoiUrl]);
+
+        assert_eq!(
+            entries.get("malformed").unwrap().validate(),
+            [
+                ValidationWarning::MalformedDoi("doi:10.1000/183".into()),
+                ValidationWarning::MalformedDoi("10.abc/184".into()),
+            ]
+        );
+    }
+
+    #[test]
+    fn check_digits() {
+        let yaml = r#"
+valid:
+    type: Book
+    title: A Book
+    serial-number:
+        isbn: [978-0-691-07916-5, 0-306-40615-2, 080442957X]
+        issn: [2412-3129, 1050-124X]
+invalid:
+    type: Book
+    title: A Book
+    serial-number:
+        isbn: [978-0-691-07916-4, 0-306-40615]
+        issn: 2412-312X
+"#;
+
+        let entries = from_yaml_str(yaml).unwrap();
+        assert!(entries.get("valid").unwrap().validate().is_empty());
+        assert_eq!(
+            entries.get("invalid").unwrap().validate(),
+            [
+                ValidationWarning::InvalidIsbn("978-0-691-07916-4".into()),
+                ValidationWarning::InvalidIsbn("0-306-40615".into()),
+                ValidationWarning::InvalidIssn("2412-312X".into()),
+            ]
+        );
+    }
+
+    #[test]
+    fn conference() {
+        let yaml = r#"
+named:
+    type: Article
+    title: ZygOS
+    event-date: 2017-10-28
+    parent:
+        type: Proceedings
+        title: Proceedings of the 26th Symposium on Operating Systems Principles
+        conference: SOSP '17
+        event-location: Shanghai, China
+unnamed:
+    type: Article
+    title: Untitled
+    parent:
+        type: Proceedings
+        title: Proceedings
+"#;
+
+        let entries = from_yaml_str(yaml).unwrap();
+        let named = entries.get("named").unwrap();
+        assert_eq!(
+            named.event_date(),
+            Some(&Date::new(2017, Some(10), Some(28)).unwrap())
+        );
+        let proceedings = &named.parents()[0];
+        assert_eq!(proceedings.conference().unwrap().to_string(), "SOSP '17");
+        assert_eq!(proceedings.event_location().unwrap().to_string(), "Shanghai, China");
+        assert!(named.validate().is_empty());
+
+        assert_eq!(
+            entries.get("unnamed").unwrap().validate(),
+            [ValidationWarning::MissingConference]
+        );
+    }
+
+    #[test]
+    fn retraction() {
+        let yaml = r#"
+unexplained:
+    type: Book
+    title: Ghost Writing
+    status: retracted
+explained:
+    type: Book
+    title: Ghost Writing
+    status: retracted
+    note: ["2nd edition", "Retracted by the publisher in 2021"]
+"#;
+
+        let entries = from_yaml_str(yaml).unwrap();
+        assert_eq!(
+            entries.get("unexplained").unwrap().validate(),
+            [ValidationWarning::RetractionWithoutNote]
+        );
+        assert!(entries.get("explained").unwrap().validate().is_empty());
+    }
+
+    #[test]
+    fn parent_only() {
+        let yaml = r#"
+orphan:
+    type: Article
+    parent:
+        type: Periodical
+        title: Il Nuovo Cimento
+empty:
+    type: Article
+    title: Structure of a Quantized Vortex in Boson Systems
+    parent: [{}]
+"#;
+
+        let entries = from_yaml_str(yaml).unwrap();
+        assert_eq!(
+            entries.get("orphan").unwrap().validate(),
+            [ValidationWarning::OrphanParentOnly]
+        );
+        assert_eq!(
+            entries.get("empty").unwrap().validate(),
+            [ValidationWarning::EmptyParent]
+        );
+
+        let mut entry = Entry::new("bare", EntryType::Book);
+        assert!(entry.validate().is_empty());
+        entry.set_parents(vec![Entry::new("bare", EntryType::Anthology)]);
+        assert_eq!(
+            entry.validate(),
+            [ValidationWarning::OrphanParentOnly, ValidationWarning::EmptyParent]
+        );
+    }
+
+    #[test]
+    fn parent_types() {
+        let yaml = r#"
+article:
+    type: Article
+    title: Structure of a Quantized Vortex in Boson Systems
+    parent:
+        title: Il Nuovo Cimento
+newspaper:
+    type: Article
+    title: Crisis
+    parent:
+        type: Newspaper
+        title: The Daily Planet
+orphan:
+    type: Article
+    title: Lost
+chapter:
+    type: Chapter
+    title: Inside
+    parent:
+        type: Book
+        title: Outside
+misplaced:
+    type: Chapter
+    title: Inside
+    parent:
+        type: Periodical
+        title: Outside
+"#;
+
+        let entries = from_yaml_str(yaml).unwrap();
+        for key in ["article", "newspaper", "chapter"] {
+            assert!(entries.get(key).unwrap().validate().is_empty(), "{}", key);
+        }
+
+        assert_eq!(
+            entries.get("orphan").unwrap().validate(),
+            [ValidationWarning::MissingParent { expected: EntryType::Periodical }]
+        );
+        assert_eq!(
+            entries.get("misplaced").unwrap().validate(),
+            [ValidationWarning::MismatchedParent { expected: EntryType::Book }]
+        );
+    }
+
+    #[test]
+    fn missing_medium() {
+        let yaml = r#"
+with:
+    type: Video
+    medium: streaming video
+without:
+    type: Audio
+"#;
+
+        let entries = from_yaml_str(yaml).unwrap();
+        let with = entries.get("with").unwrap();
+        assert_eq!(with.medium().unwrap().to_string(), "streaming video");
+        assert!(with.validate().is_empty());
+        assert_eq!(
+            entries.get("without").unwrap().validate(),
+            [ValidationWarning::MissingMedium]
+        );
+
+        let mut entry = Entry::new("book", EntryType::Book);
+        assert!(entry.validate().is_empty());
+        entry.set_medium(FormatString::with_value("print"));
+        assert_eq!(entry.medium(), Some(&FormatString::with_value("print")));
+    }
+}
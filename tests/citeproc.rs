@@ -11,10 +11,10 @@ use common::{ensure_repo, iter_files_with_name, CACHE_PATH};
 
 use citationberg::json as csl_json;
 use hayagriva::archive::{locales, ArchivedStyle};
-use hayagriva::io::from_biblatex_str;
+use hayagriva::io::{from_biblatex_str, from_yaml_str};
 use hayagriva::{
-    BibliographyDriver, BibliographyRequest, CitationItem, CitationRequest, CitePurpose,
-    Entry, LocatorPayload, SpecificLocator,
+    AnonymousPolicy, BibliographyDriver, BibliographyRequest, CitationItem,
+    CitationRequest, CitePurpose, Entry, LocatorPayload, SpecificLocator,
 };
 use unscanny::Scanner;
 
@@ -636,5 +636,627 @@ fn access_date() {
         .content
         .write_buf(&mut buf, hayagriva::BufWriteFormat::Plain)
         .unwrap();
-    assert_eq!(buf, "Retrieved 2021, from https://example.com/");
+    // The entry has no date, so APA prints its "(n.d.)" term.
+    assert_eq!(buf, "(n.d.). Retrieved 2021, from https://example.com/");
+}
+
+#[test]
+fn accessed_date() {
+    let style = ArchivedStyle::by_name("apa").unwrap().get();
+    let locales = locales();
+    let Style::Independent(style) = style else {
+        panic!("test has dependent style");
+    };
+
+    let lib = from_yaml_str(
+        r#"test:
+        type: Web
+        url: { value: https://example.com, date: 2021 }
+        accessed: 2022
+      "#,
+    )
+    .unwrap();
+    let entry = lib.get("test").unwrap();
+
+    let mut driver: BibliographyDriver<'_, Entry> = BibliographyDriver::new();
+    driver.citation(CitationRequest::new(
+        vec![CitationItem::new(entry, None, None, false, None)],
+        &style,
+        None,
+        &locales,
+        Some(1),
+    ));
+
+    let rendered = driver.finish(BibliographyRequest::new(&style, None, &locales));
+    let mut buf = String::new();
+    rendered.bibliography.unwrap().items[0]
+        .content
+        .write_buf(&mut buf, hayagriva::BufWriteFormat::Plain)
+        .unwrap();
+    assert_eq!(buf, "(n.d.). Retrieved 2022, from https://example.com/");
+}
+
+#[test]
+fn chapter_container() {
+    let style = ArchivedStyle::by_name("apa").unwrap().get();
+    let locales = locales();
+    let Style::Independent(style) = style else {
+        panic!("test has dependent style");
+    };
+
+    let lib = from_yaml_str(
+        r#"chapter:
+        type: Chapter
+        title: The Chapter
+        author: Doe, Jane
+        date: 2020
+        page-range: 10-20
+        parent:
+            type: Anthology
+            title: The Book
+            editor: Ed, John
+            publisher: Publisher
+      "#,
+    )
+    .unwrap();
+    let entry = lib.get("chapter").unwrap();
+
+    let mut driver: BibliographyDriver<'_, Entry> = BibliographyDriver::new();
+    driver.citation(CitationRequest::new(
+        vec![CitationItem::new(entry, None, None, false, None)],
+        &style,
+        None,
+        &locales,
+        Some(1),
+    ));
+
+    let rendered = driver.finish(BibliographyRequest::new(&style, None, &locales));
+    let mut buf = String::new();
+    rendered.bibliography.unwrap().items[0]
+        .content
+        .write_buf(&mut buf, hayagriva::BufWriteFormat::Html)
+        .unwrap();
+    assert_eq!(
+        buf,
+        "Doe, J. (2020). The Chapter. In J. Ed (Ed.), \
+         <span style=\"font-style: italic;\">The Book</span> (pp. 10–20). Publisher."
+    );
+}
+
+#[test]
+fn undated() {
+    let style = ArchivedStyle::by_name("apa").unwrap().get();
+    let locales = locales();
+    let Style::Independent(style) = style else {
+        panic!("test has dependent style");
+    };
+
+    let lib = from_yaml_str(
+        r#"undated:
+        type: Book
+        title: Undated Book
+        author: Doe, Jane
+      "#,
+    )
+    .unwrap();
+    let entry = lib.get("undated").unwrap();
+
+    let mut driver: BibliographyDriver<'_, Entry> = BibliographyDriver::new();
+    driver.citation(CitationRequest::new(
+        vec![CitationItem::new(entry, None, None, false, None)],
+        &style,
+        None,
+        &locales,
+        Some(1),
+    ));
+
+    let rendered = driver.finish(BibliographyRequest::new(&style, None, &locales));
+    let mut buf = String::new();
+    rendered.citations[0]
+        .citation
+        .write_buf(&mut buf, hayagriva::BufWriteFormat::Plain)
+        .unwrap();
+    assert_eq!(buf, "(Doe, n.d.)");
+
+    let mut buf = String::new();
+    rendered.bibliography.unwrap().items[0]
+        .content
+        .write_buf(&mut buf, hayagriva::BufWriteFormat::Plain)
+        .unwrap();
+    assert_eq!(buf, "Doe, J. (n.d.). Undated Book.");
+}
+
+#[test]
+fn anonymous() {
+    // The style, not the policy, capitalizes the "anonymous" term.
+    let style = Style::from_xml(
+        r#"<?xml version="1.0" encoding="utf-8"?>
+        <style xmlns="http://purl.org/net/xbiblio/csl" class="in-text" version="1.0">
+            <info>
+                <id>anonymous</id>
+                <title>Anonymous</title>
+                <updated>2024-01-01T00:00:00+00:00</updated>
+            </info>
+            <macro name="author">
+                <names variable="author">
+                    <substitute>
+                        <names variable="editor"/>
+                        <text variable="title"/>
+                    </substitute>
+                </names>
+            </macro>
+            <citation>
+                <layout>
+                    <text macro="author"/>
+                </layout>
+            </citation>
+            <bibliography>
+                <layout>
+                    <group delimiter=". ">
+                        <text macro="author" text-case="capitalize-first"/>
+                        <date variable="issued">
+                            <date-part name="year"/>
+                        </date>
+                        <text variable="title"/>
+                        <text variable="publisher"/>
+                    </group>
+                </layout>
+            </bibliography>
+        </style>"#,
+    )
+    .unwrap();
+    let locales = locales();
+    let Style::Independent(style) = style else {
+        panic!("test has dependent style");
+    };
+
+    let lib = from_yaml_str(
+        r#"beowulf:
+        type: Book
+        title: Beowulf
+        publisher: Penguin
+        date: 1999
+      "#,
+    )
+    .unwrap();
+    let entry = lib.get("beowulf").unwrap();
+
+    let render = |policy| {
+        let mut driver: BibliographyDriver<'_, Entry> = BibliographyDriver::new();
+        driver.set_anonymous_policy(policy);
+        driver.citation(CitationRequest::new(
+            vec![CitationItem::new(entry, None, None, false, None)],
+            &style,
+            None,
+            &locales,
+            Some(1),
+        ));
+
+        let rendered = driver.finish(BibliographyRequest::new(&style, None, &locales));
+        let mut buf = String::new();
+        rendered.bibliography.unwrap().items[0]
+            .content
+            .write_buf(&mut buf, hayagriva::BufWriteFormat::Plain)
+            .unwrap();
+        buf
+    };
+
+    assert_eq!(render(AnonymousPolicy::UseTitle), "Beowulf. 1999. Penguin");
+    assert_eq!(render(AnonymousPolicy::Anonymous), "Anonymous. 1999. Beowulf. Penguin");
+    assert_eq!(render(AnonymousPolicy::Empty), "1999. Beowulf. Penguin");
+}
+
+#[test]
+fn citation_numbers_follow_first_use() {
+    let style = ArchivedStyle::by_name("ieee").unwrap().get();
+    let locales = locales();
+    let Style::Independent(style) = style else {
+        panic!("test has dependent style");
+    };
+
+    let lib = from_yaml_str(
+        r#"
+        alpha:
+            type: Book
+            title: Alpha
+            date: 2001
+        beta:
+            type: Book
+            title: Beta
+            date: 2002
+        gamma:
+            type: Book
+            title: Gamma
+            date: 2003
+        "#,
+    )
+    .unwrap();
+
+    let mut driver: BibliographyDriver<'_, Entry> = BibliographyDriver::new();
+    for key in ["gamma", "alpha", "gamma", "beta", "alpha"] {
+        driver.citation(CitationRequest::from_items(
+            vec![CitationItem::with_entry(lib.get(key).unwrap())],
+            &style,
+            &locales,
+        ));
+    }
+
+    let rendered = driver.finish(BibliographyRequest::new(&style, None, &locales));
+    let citations: Vec<_> = rendered
+        .citations
+        .iter()
+        .map(|c| {
+            let mut buf = String::new();
+            c.citation
+                .write_buf(&mut buf, hayagriva::BufWriteFormat::Plain)
+                .unwrap();
+            buf
+        })
+        .collect();
+
+    assert_eq!(citations, ["[1]", "[2]", "[1]", "[3]", "[2]"]);
+}
+
+#[test]
+fn truncated_authors() {
+    let style = ArchivedStyle::by_name("chicago-author-date").unwrap().get();
+    let locales = locales();
+    let Style::Independent(style) = style else {
+        panic!("test has dependent style");
+    };
+
+    let lib = from_yaml_str(
+        r#"
+        survey:
+            type: Article
+            title: A Survey
+            author: ["Smith, John", "Doe, Jane", "et al."]
+            date: 2010
+        "#,
+    )
+    .unwrap();
+
+    let mut driver: BibliographyDriver<'_, Entry> = BibliographyDriver::new();
+    driver.citation(CitationRequest::from_items(
+        vec![CitationItem::with_entry(lib.get("survey").unwrap())],
+        &style,
+        &locales,
+    ));
+
+    let rendered = driver.finish(BibliographyRequest::new(&style, None, &locales));
+    let mut buf = String::new();
+    rendered.citations[0]
+        .citation
+        .write_buf(&mut buf, hayagriva::BufWriteFormat::Plain)
+        .unwrap();
+    assert_eq!(buf, "(Smith, Doe, et al. 2010)");
+
+    buf.clear();
+    rendered.bibliography.unwrap().items[0]
+        .content
+        .write_buf(&mut buf, hayagriva::BufWriteFormat::Plain)
+        .unwrap();
+    assert_eq!(buf, "Smith, John, Jane Doe, et al. 2010. “A Survey”");
+}
+
+#[test]
+fn retrieval_clause() {
+    let style = ArchivedStyle::by_name("apa").unwrap().get();
+    let locales = locales();
+    let Style::Independent(style) = style else {
+        panic!("test has dependent style");
+    };
+
+    let lib = from_yaml_str(
+        r#"
+        visited:
+            type: Web
+            title: Home
+            author: Doe, Jane
+            url: { value: https://example.com, date: 2020-05-01 }
+        accessed:
+            type: Web
+            title: Home
+            author: Doe, Jane
+            url: https://example.com
+            accessed: 2020-05-01
+        unvisited:
+            type: Web
+            title: Home
+            author: Doe, Jane
+            url: https://example.com
+        "#,
+    )
+    .unwrap();
+
+    let render = |key| {
+        let mut driver: BibliographyDriver<'_, Entry> = BibliographyDriver::new();
+        driver.citation(CitationRequest::from_items(
+            vec![CitationItem::with_entry(lib.get(key).unwrap())],
+            &style,
+            &locales,
+        ));
+
+        let rendered = driver.finish(BibliographyRequest::new(&style, None, &locales));
+        let mut buf = String::new();
+        rendered.bibliography.unwrap().items[0]
+            .content
+            .write_buf(&mut buf, hayagriva::BufWriteFormat::Plain)
+            .unwrap();
+        buf
+    };
+
+    assert_eq!(
+        render("visited"),
+        "Doe, J. (n.d.). Home. Retrieved May 1, 2020, from https://example.com/"
+    );
+    assert_eq!(
+        render("accessed"),
+        "Doe, J. (n.d.). Home. Retrieved May 1, 2020, from https://example.com/"
+    );
+    assert_eq!(render("unvisited"), "Doe, J. (n.d.). Home. https://example.com/");
+}
+
+#[test]
+fn narrative_citation() {
+    let style = ArchivedStyle::by_name("apa").unwrap().get();
+    let locales = locales();
+    let Style::Independent(style) = style else {
+        panic!("test has dependent style");
+    };
+
+    let lib = from_yaml_str(
+        r#"
+        smith:
+            type: Book
+            title: Arguments
+            author: Smith, Jane
+            date: 2020
+        "#,
+    )
+    .unwrap();
+    let entry = lib.get("smith").unwrap();
+
+    let render = |purpose| {
+        let mut driver: BibliographyDriver<'_, Entry> = BibliographyDriver::new();
+        driver.citation(CitationRequest::from_items(
+            vec![CitationItem::new(
+                entry,
+                Some(SpecificLocator(Locator::Page, LocatorPayload::Str("5"))),
+                None,
+                false,
+                purpose,
+            )],
+            &style,
+            &locales,
+        ));
+
+        let rendered = driver.finish(BibliographyRequest::new(&style, None, &locales));
+        let mut buf = String::new();
+        rendered.citations[0]
+            .citation
+            .write_buf(&mut buf, hayagriva::BufWriteFormat::Plain)
+            .unwrap();
+        buf
+    };
+
+    assert_eq!(render(None), "(Smith, 2020, p. 5)");
+    assert_eq!(render(Some(CitePurpose::Prose)), "Smith (2020, p. 5)");
+}
+
+#[test]
+fn publication_status() {
+    let style = ArchivedStyle::by_name("apa").unwrap().get();
+    let locales = locales();
+    let Style::Independent(style) = style else {
+        panic!("test has dependent style");
+    };
+
+    let lib = from_yaml_str(
+        r#"
+        pending:
+            type: Book
+            title: Pending
+            author: Smith, Jane
+            status: in press
+        "#,
+    )
+    .unwrap();
+
+    let mut driver: BibliographyDriver<'_, Entry> = BibliographyDriver::new();
+    driver.citation(CitationRequest::from_items(
+        vec![CitationItem::with_entry(lib.get("pending").unwrap())],
+        &style,
+        &locales,
+    ));
+
+    let rendered = driver.finish(BibliographyRequest::new(&style, None, &locales));
+    let mut buf = String::new();
+    rendered.citations[0]
+        .citation
+        .write_buf(&mut buf, hayagriva::BufWriteFormat::Plain)
+        .unwrap();
+    assert_eq!(buf, "(Smith, in press)");
+
+    buf.clear();
+    rendered.bibliography.unwrap().items[0]
+        .content
+        .write_buf(&mut buf, hayagriva::BufWriteFormat::Plain)
+        .unwrap();
+    assert_eq!(buf, "Smith, J. (in press). Pending.");
+}
+
+#[test]
+fn editor_et_al() {
+    let style = Style::from_xml(
+        r#"<?xml version="1.0" encoding="utf-8"?>
+        <style xmlns="http://purl.org/net/xbiblio/csl" class="in-text" version="1.0">
+            <info>
+                <id>editors</id>
+                <title>Editors</title>
+                <updated>2024-01-01T00:00:00+00:00</updated>
+            </info>
+            <citation>
+                <layout>
+                    <names variable="author">
+                        <name et-al-min="4" et-al-use-first="3" initialize-with=". "/>
+                    </names>
+                    <text value="; "/>
+                    <names variable="editor">
+                        <name et-al-min="3" et-al-use-first="1" initialize-with=". "/>
+                    </names>
+                </layout>
+            </citation>
+        </style>"#,
+    )
+    .unwrap();
+    let locales = locales();
+    let Style::Independent(style) = style else {
+        panic!("test has dependent style");
+    };
+
+    let lib = from_yaml_str(
+        r#"
+        volume:
+            type: Anthology
+            title: Collected Essays
+            author: ["Abel, Anna", "Brandt, Bea", "Conti, Carla"]
+            editor:
+                - Doe, Dora
+                - Eck, Emil
+                - Fay, Fritz
+                - Gee, Gina
+                - Hall, Hugo
+                - Ives, Ida
+                - Jung, Jan
+                - Kahn, Kai
+        "#,
+    )
+    .unwrap();
+
+    let mut driver: BibliographyDriver<'_, Entry> = BibliographyDriver::new();
+    driver.citation(CitationRequest::from_items(
+        vec![CitationItem::with_entry(lib.get("volume").unwrap())],
+        &style,
+        &locales,
+    ));
+
+    let rendered = driver.finish(BibliographyRequest::new(&style, None, &locales));
+    let mut buf = String::new();
+    rendered.citations[0]
+        .citation
+        .write_buf(&mut buf, hayagriva::BufWriteFormat::Plain)
+        .unwrap();
+    assert_eq!(buf, "A. Abel, B. Brandt, C. Conti; D. Doe et al.");
+}
+
+#[test]
+fn single_page_first() {
+    let style = Style::from_xml(
+        r#"<?xml version="1.0" encoding="utf-8"?>
+        <style xmlns="http://purl.org/net/xbiblio/csl" class="in-text" version="1.0">
+            <info>
+                <id>pages</id>
+                <title>Pages</title>
+                <updated>2024-01-01T00:00:00+00:00</updated>
+            </info>
+            <citation>
+                <layout>
+                    <text variable="page-first"/>
+                </layout>
+            </citation>
+        </style>"#,
+    )
+    .unwrap();
+    let locales = locales();
+    let Style::Independent(style) = style else {
+        panic!("test has dependent style");
+    };
+
+    let lib = from_yaml_str(
+        r#"
+        range:
+            type: Article
+            page-range: 12-20
+        integer:
+            type: Article
+            page-range: 5
+        string:
+            type: Article
+            page-range: "5"
+        "#,
+    )
+    .unwrap();
+
+    let render = |key| {
+        let mut driver: BibliographyDriver<'_, Entry> = BibliographyDriver::new();
+        driver.citation(CitationRequest::from_items(
+            vec![CitationItem::with_entry(lib.get(key).unwrap())],
+            &style,
+            &locales,
+        ));
+
+        let rendered = driver.finish(BibliographyRequest::new(&style, None, &locales));
+        let mut buf = String::new();
+        rendered.citations[0]
+            .citation
+            .write_buf(&mut buf, hayagriva::BufWriteFormat::Plain)
+            .unwrap();
+        buf
+    };
+
+    assert_eq!(render("range"), "12");
+    assert_eq!(render("integer"), "5");
+    assert_eq!(render("string"), "5");
+}
+
+#[test]
+fn title_language() {
+    let style = ArchivedStyle::by_name("chicago-author-date").unwrap().get();
+    let locales = locales();
+    let Style::Independent(style) = style else {
+        panic!("test has dependent style");
+    };
+
+    let lib = from_yaml_str(
+        r#"
+        german-title:
+            type: Book
+            title:
+                value: der process
+                language: de
+            author: Kafka, Franz
+            date: 1925
+            language: en
+        english-title:
+            type: Book
+            title:
+                value: the trial
+                language: en
+            author: Kafka, Franz
+            date: 1925
+            language: de
+        "#,
+    )
+    .unwrap();
+
+    let render = |key| {
+        let mut driver: BibliographyDriver<'_, Entry> = BibliographyDriver::new();
+        driver.citation(CitationRequest::from_items(
+            vec![CitationItem::with_entry(lib.get(key).unwrap())],
+            &style,
+            &locales,
+        ));
+
+        let rendered = driver.finish(BibliographyRequest::new(&style, None, &locales));
+        let mut buf = String::new();
+        rendered.bibliography.unwrap().items[0]
+            .content
+            .write_buf(&mut buf, hayagriva::BufWriteFormat::Plain)
+            .unwrap();
+        buf
+    };
+
+    assert_eq!(render("german-title"), "Kafka, Franz. 1925. der process");
+    assert_eq!(render("english-title"), "Kafka, Franz. 1925. The Trial");
 }